@@ -0,0 +1,305 @@
+//! Times parse, part A and part B of every day on the committed inputs (or the ones of `aoc.toml`) and on synthetic inputs.
+//!
+//! ```sh
+//! cargo bench -p aoc --bench days -- [--day <N>] [--sizes 1000,10000] [--quick] [--save-baseline <name>] [--baseline <name>]
+//! ```
+//!
+//! Every phase is warmed up, then sampled: the reported time is the median of samples.
+//! `--save-baseline` stores timings in `target/bench/<name>.txt`, `--baseline` adds the change against them.
+
+use aoc_common::{Error, Params, Solution};
+use std::{
+    collections::HashMap,
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_SIZES: &[usize] = &[1_000, 10_000];
+
+struct Config {
+    day: Option<u8>,
+    sizes: Vec<usize>,
+    warm_up: Duration,
+    measurement: Duration,
+    save_baseline: Option<String>,
+    baseline: Option<String>,
+}
+
+impl Config {
+    fn from_env() -> Self {
+        let mut config = Config {
+            day: None,
+            sizes: DEFAULT_SIZES.to_vec(),
+            warm_up: Duration::from_millis(300),
+            measurement: Duration::from_secs(1),
+            save_baseline: None,
+            baseline: None,
+        };
+        let mut args = std::env::args().skip(1);
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .unwrap_or_else(|| exit_with_usage(&format!("Missing value of: {}.", arg)))
+            };
+
+            match arg.as_str() {
+                "--day" => {
+                    let day = value();
+                    config.day = Some(day.parse().unwrap_or_else(|_| {
+                        exit_with_usage(&format!("Invalid day: \"{}\".", day))
+                    }));
+                }
+                "--sizes" => {
+                    let sizes = value();
+                    config.sizes = sizes
+                        .split(',')
+                        .map(|size| size.parse::<usize>())
+                        .collect::<Result<_, _>>()
+                        .unwrap_or_else(|_| {
+                            exit_with_usage(&format!("Invalid sizes: \"{}\".", sizes))
+                        });
+                }
+                "--quick" => {
+                    config.warm_up = Duration::from_millis(50);
+                    config.measurement = Duration::from_millis(200);
+                }
+                "--save-baseline" => config.save_baseline = Some(value()),
+                "--baseline" => config.baseline = Some(value()),
+                // Passed by `cargo bench`.
+                "--bench" => {}
+                _ => exit_with_usage(&format!("Unexpected argument: \"{}\".", arg)),
+            }
+        }
+
+        config
+    }
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!("Usage: cargo bench -p aoc --bench days -- [--day <N>] [--sizes <N,M...>] [--quick] [--save-baseline <name>] [--baseline <name>]");
+    process::exit(2)
+}
+
+/// Median time of a phase, in nanoseconds.
+type Timings = HashMap<(u8, String, &'static str), u128>;
+
+const PHASES: [&str; 3] = ["parse", "part A", "part B"];
+
+/// Times `routine` like criterion does: warm up to estimate iteration time, then take samples
+/// each running enough iterations to fill measurement time.
+fn measure<T>(config: &Config, mut routine: impl FnMut() -> T) -> u128 {
+    let mut iterations = 0u128;
+    let start = Instant::now();
+
+    while start.elapsed() < config.warm_up || iterations == 0 {
+        black_box(routine());
+        iterations += 1;
+    }
+
+    let estimate = start.elapsed().as_nanos() / iterations;
+    let samples = (config.measurement.as_nanos() / estimate.max(1)).clamp(5, 50);
+    let iterations_per_sample = (config.measurement.as_nanos() / samples / estimate.max(1)).max(1);
+
+    let mut samples = (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations_per_sample {
+                black_box(routine());
+            }
+            start.elapsed().as_nanos() / iterations_per_sample
+        })
+        .collect::<Vec<u128>>();
+
+    samples.sort_unstable();
+    samples[samples.len() / 2]
+}
+
+/// Times every phase of a day on an input, input must be valid.
+fn bench_day<S: Solution>(config: &Config, input: &str, name: &str, timings: &mut Timings) {
+    let fail = |err: Error| -> ! {
+        eprintln!("Day {} ({}) failed on {} input:", S::DAY, S::TITLE, name);
+        err.with_file(name).exit()
+    };
+    let parsed = S::parse(&mut input.as_bytes()).unwrap_or_else(|err| fail(err));
+    let params = Params::defaults(S::PARAMS);
+
+    if let Err(err) = S::part_a(&parsed, &params).and_then(|_| S::part_b(&parsed, &params)) {
+        fail(err);
+    }
+
+    let results = [
+        measure(config, || S::parse(&mut input.as_bytes())),
+        measure(config, || S::part_a(&parsed, &params)),
+        measure(config, || S::part_b(&parsed, &params)),
+    ];
+
+    for (phase, nanos) in PHASES.into_iter().zip(results) {
+        timings.insert((S::DAY, name.to_string(), phase), nanos);
+    }
+}
+
+/// Bench a day on its committed input, when there is one, and on synthetic inputs.
+fn bench<S: Solution>(config: &Config, rows: &mut Vec<(u8, String)>, timings: &mut Timings) {
+    if config.day.is_some_and(|day| day != S::DAY) {
+        return;
+    }
+
+    let mut inputs = Vec::new();
+
+    if let Ok(input) = fs::read_to_string(input_path(S::DAY)) {
+        inputs.push((String::from("real"), input));
+    }
+    for size in &config.sizes {
+        if let Some(input) = aoc_gen::generate(S::DAY, *size, aoc_gen::DEFAULT_SEED) {
+            inputs.push((format!("synthetic {}", size), input));
+        }
+    }
+
+    for (name, input) in inputs {
+        eprintln!("Benchmarking day {} on {} input...", S::DAY, name);
+        bench_day::<S>(config, &input, &name, timings);
+        rows.push((S::DAY, name));
+    }
+}
+
+/// Input of a day set in the config file, like `aoc run` does, else the committed one.
+fn input_path(day: u8) -> PathBuf {
+    let config_path = std::env::var_os("AOC_CONFIG")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join("aoc.toml"));
+    let configured = match aoc_common::config::Config::load(&config_path) {
+        Ok(config) => config.input(day),
+        Err(_) if !config_path.exists() => None,
+        Err(err) => err.exit(),
+    };
+
+    configured.unwrap_or_else(|| workspace_root().join(format!("day_{}/src/input.txt", day)))
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate lives inside the workspace.")
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    workspace_root()
+        .join("target/bench")
+        .join(format!("{}.txt", name))
+}
+
+/// Baseline lines are: `<day>\t<input>\t<phase>\t<nanos>`.
+fn save_baseline(name: &str, timings: &Timings) {
+    let path = baseline_path(name);
+    let mut lines = timings
+        .iter()
+        .map(|((day, input, phase), nanos)| format!("{}\t{}\t{}\t{}", day, input, phase, nanos))
+        .collect::<Vec<String>>();
+
+    lines.sort();
+
+    if let Err(err) = fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(&path, lines.join("\n") + "\n"))
+    {
+        Error::from(err).exit();
+    }
+    eprintln!("Saved baseline in: {}", path.display());
+}
+
+fn load_baseline(name: &str) -> Timings {
+    let path = baseline_path(name);
+    let content = fs::read_to_string(&path).unwrap_or_else(|err| {
+        exit_with_usage(&format!(
+            "Unable to read baseline: {}. {}",
+            path.display(),
+            err
+        ))
+    });
+
+    content
+        .lines()
+        .filter_map(
+            |line| match line.split('\t').collect::<Vec<&str>>().as_slice() {
+                [day, input, phase, nanos] => Some((
+                    (
+                        day.parse().ok()?,
+                        input.to_string(),
+                        *PHASES.iter().find(|p| p == &phase)?,
+                    ),
+                    nanos.parse().ok()?,
+                )),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+fn format_nanos(nanos: u128) -> String {
+    match nanos {
+        0..=999 => format!("{} ns", nanos),
+        1_000..=999_999 => format!("{:.1} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.1} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+fn print_summary(rows: &[(u8, String)], timings: &Timings, baseline: Option<&Timings>) {
+    println!(
+        "| {:>3} | {:<16} | {:>22} | {:>22} | {:>22} |",
+        "day", "input", PHASES[0], PHASES[1], PHASES[2]
+    );
+    println!(
+        "|----:|:-----------------|{}",
+        "-----------------------:|".repeat(3)
+    );
+
+    for (day, input) in rows {
+        let cells = PHASES
+            .iter()
+            .map(|phase| {
+                let key = (*day, input.clone(), *phase);
+                let nanos = timings[&key];
+
+                match baseline.and_then(|b| b.get(&key)) {
+                    Some(before) if *before > 0 => format!(
+                        "{} ({:+.1}%)",
+                        format_nanos(nanos),
+                        (nanos as f64 / *before as f64 - 1.0) * 100.0
+                    ),
+                    _ => format_nanos(nanos),
+                }
+            })
+            .collect::<Vec<String>>();
+
+        println!(
+            "| {:>3} | {:<16} | {:>22} | {:>22} | {:>22} |",
+            day, input, cells[0], cells[1], cells[2]
+        );
+    }
+}
+
+fn main() {
+    let config = Config::from_env();
+    let baseline = config.baseline.as_deref().map(load_baseline);
+    let mut rows = Vec::new();
+    let mut timings = Timings::new();
+
+    bench::<day_1::Puzzle>(&config, &mut rows, &mut timings);
+    bench::<day_2::Puzzle>(&config, &mut rows, &mut timings);
+    bench::<day_3::Puzzle>(&config, &mut rows, &mut timings);
+    bench::<day_4::Puzzle>(&config, &mut rows, &mut timings);
+    bench::<day_5::Puzzle>(&config, &mut rows, &mut timings);
+    bench::<day_6::Puzzle>(&config, &mut rows, &mut timings);
+    bench::<day_7::Puzzle>(&config, &mut rows, &mut timings);
+
+    print_summary(&rows, &timings, baseline.as_ref());
+
+    if let Some(name) = &config.save_baseline {
+        save_baseline(name, &timings);
+    }
+}
@@ -0,0 +1,158 @@
+use aoc_common::{Error, ParseError, Part};
+use std::{fmt, fs, io, path::Path};
+
+/// Answer known to be correct for a day, part and input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    /// See [`hash_input`].
+    pub input_hash: String,
+    pub answer: String,
+}
+
+/// Answers registry, stored as a text file where each line is: `<day> <part> <input hash> <answer>`.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut answers = Vec::new();
+
+        for (idx, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.splitn(4, ' ').collect::<Vec<&str>>();
+            let invalid_line = || {
+                ParseError::whole_line(
+                    "Expected a line like: <day> <part> <input hash> <answer>.",
+                    idx + 1,
+                    line,
+                )
+            };
+
+            match fields.as_slice() {
+                [day, part, input_hash, answer] if !answer.is_empty() => answers.push(Answer {
+                    day: day.parse::<u8>().map_err(|_| invalid_line())?,
+                    part: part.parse::<Part>().map_err(|_| invalid_line())?,
+                    input_hash: input_hash.to_string(),
+                    answer: answer.to_string(),
+                }),
+                _ => return Err(invalid_line()),
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Load registry from a file, a missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|err| Error::from(err).with_file(path.display().to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// Expected answer for a day, part and input hash.
+    pub fn expected(&self, day: u8, part: Part, input_hash: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input_hash == input_hash)
+            .map(|a| a.answer.as_str())
+    }
+
+    /// Add an answer keeping registry sorted by day and part.
+    pub fn add(&mut self, answer: Answer) {
+        self.answers.push(answer);
+        self.answers
+            .sort_by_key(|a| (a.day, a.part == Part::B, a.input_hash.clone()));
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part input_hash answer")?;
+
+        for a in &self.answers {
+            writeln!(f, "{} {} {} {}", a.day, a.part, a.input_hash, a.answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Hash of puzzle input (64 bit FNV-1a), used to bind answers to the input they come from.
+pub fn hash_input(content: &str) -> String {
+    let hash = content.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_input, Answer, Answers};
+    use aoc_common::Part;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", "cbf29ce484222325")]
+    #[case("a", "af63dc4c8601ec8c")]
+    fn should_hash_input(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(expected, hash_input(input));
+    }
+
+    #[test]
+    fn should_parse_answers() {
+        let answers = Answers::parse("# comment\n\n1 A 00ff 68467\n5 B 00aa MQSH JM\n").unwrap();
+
+        assert_eq!(Some("68467"), answers.expected(1, Part::A, "00ff"));
+        assert_eq!(Some("MQSH JM"), answers.expected(5, Part::B, "00aa"));
+        assert_eq!(None, answers.expected(1, Part::B, "00ff"));
+        assert_eq!(None, answers.expected(1, Part::A, "00aa"));
+    }
+
+    #[rstest]
+    #[case("1 A 00ff")]
+    #[case("x A 00ff 1")]
+    #[case("1 C 00ff 1")]
+    fn should_not_parse_answers(#[case] content: &str) {
+        let err = Answers::parse(content).unwrap_err();
+
+        assert_eq!(1, err.line);
+    }
+
+    #[test]
+    fn should_format_sorted_answers() {
+        let mut answers = Answers::default();
+
+        for (day, part) in [(2, Part::B), (1, Part::B), (2, Part::A)] {
+            answers.add(Answer {
+                day,
+                part,
+                input_hash: String::from("00ff"),
+                answer: day.to_string(),
+            });
+        }
+
+        let content = answers.to_string();
+
+        assert_eq!(
+            "# day part input_hash answer\n1 B 00ff 1\n2 A 00ff 2\n2 B 00ff 2\n",
+            content
+        );
+        assert_eq!(answers, Answers::parse(&content).unwrap());
+    }
+}
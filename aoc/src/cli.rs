@@ -0,0 +1,806 @@
+use aoc_common::{config::ParamOverride, ArgsError, Format, Input, Part};
+use std::{path::PathBuf, time::Duration};
+
+pub const USAGE: &str = "Usage:
+    aoc run --day <N> [--part <A | B>] [--format <text | json> | --profile] [-v | -vv] [input path | - | --input-str <input>]
+    aoc run --all [--part <A | B>] [--format <text | json> | --profile | --jobs <N>] [-v | -vv]
+        run options: [--config <file>] [--param [day_<N>.]<name>=<N>]... [--input-dir <dir>]
+    aoc verify [--day <N>] [--record]
+    aoc gen --day <N> --size <N> [--seed <N>] [--marker-at <position, day 6 only>]
+    aoc new <N> [--title <title>]
+    aoc fetch [--day <N>] [--year <N>]
+    aoc viz --day <N> [--part <A | B>] [--fps <N>] [--svg <dir>] [--gif <file>] [input path | - | --input-str <input>]
+    aoc serve [--host <address>] [--port <N>] [--timeout <ms>] [-v | -vv]
+    aoc watch --day <N> [--interval <ms>]
+    aoc readme";
+
+/// Which days to run.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Days {
+    One(u8),
+    All,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub days: Days,
+    /// When missing both parts are run.
+    pub part: Option<Part>,
+    /// When missing the committed input of each day is used, `-` is stdin.
+    pub input: Option<Input>,
+    pub format: Format,
+    /// Report time and memory of each phase: reading, parsing and solving.
+    pub profile: bool,
+    /// Number of `-v`, see [`aoc_common::init_tracing`].
+    pub verbosity: u8,
+    /// Run days on this many threads, then print a summary table.
+    pub jobs: Option<usize>,
+    /// When missing `AOC_CONFIG` is used, else `aoc.toml` of the workspace when it exists.
+    pub config: Option<PathBuf>,
+    /// Parameters changed over config ones.
+    pub params: Vec<ParamOverride>,
+    /// Inputs are `day_N.txt` files in this dir, over config ones.
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    /// When missing every day is verified.
+    pub day: Option<u8>,
+    /// Record answers missing in registry.
+    pub record: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GenArgs {
+    pub day: u8,
+    /// Number of items of day format, see [`aoc_gen::Generator`].
+    pub size: usize,
+    pub seed: u64,
+    /// Position of day 6 marker, end of signal when missing.
+    pub marker_at: Option<usize>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NewArgs {
+    pub day: u8,
+    /// Puzzle title, as shown in puzzle description.
+    pub title: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FetchArgs {
+    /// When missing every day is fetched.
+    pub day: Option<u8>,
+    pub year: u16,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VizArgs {
+    pub day: u8,
+    pub part: Part,
+    /// When missing the committed input of the day is used, `-` is stdin.
+    pub input: Option<Input>,
+    /// Frames per second, of terminal playback and gif.
+    pub fps: f64,
+    /// Write a svg file per frame in this dir instead of playing frames.
+    pub svg: Option<PathBuf>,
+    /// Write an animated gif instead of playing frames.
+    pub gif: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ServeArgs {
+    /// Address to listen on, localhost by default.
+    pub host: String,
+    /// `0` picks a free port.
+    pub port: u16,
+    /// Time a client has to send its whole request.
+    pub timeout: Duration,
+    /// Number of `-v`, see [`aoc_common::init_tracing`].
+    pub verbosity: u8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct WatchArgs {
+    pub day: u8,
+    /// How often files are checked for changes.
+    pub interval: Duration,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Gen(GenArgs),
+    New(NewArgs),
+    Fetch(FetchArgs),
+    Viz(VizArgs),
+    Serve(ServeArgs),
+    Watch(WatchArgs),
+    Readme,
+    Help,
+}
+
+impl Command {
+    /// Parse args (program name excluded).
+    pub fn parse<I>(args: I) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+
+        match args.next().as_deref() {
+            Some("run") => parse_run(args).map(Command::Run),
+            Some("verify") => parse_verify(args).map(Command::Verify),
+            Some("gen") => parse_gen(args).map(Command::Gen),
+            Some("new") => parse_new(args).map(Command::New),
+            Some("fetch") => parse_fetch(args).map(Command::Fetch),
+            Some("viz") => parse_viz(args).map(Command::Viz),
+            Some("serve") => parse_serve(args).map(Command::Serve),
+            Some("watch") => parse_watch(args).map(Command::Watch),
+            Some("readme") => match args.next() {
+                Some(arg) => Err(ArgsError::UnexpectedArg(arg)),
+                None => Ok(Command::Readme),
+            },
+            Some("help") | Some("--help") | Some("-h") | None => Ok(Command::Help),
+            Some(other) => Err(ArgsError::UnexpectedArg(other.to_string())),
+        }
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<RunArgs, ArgsError> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut format = Format::default();
+    let mut profile = false;
+    let mut verbosity = 0;
+    let mut jobs = None;
+    let mut config = None;
+    let mut params = Vec::new();
+    let mut input_dir = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(args.next())?),
+            "--jobs" | "-j" => jobs = Some(parse_number(args.next(), "jobs")?),
+            "--config" => {
+                config = Some(
+                    args.next()
+                        .ok_or(ArgsError::MissingOption("config"))?
+                        .into(),
+                )
+            }
+            "--param" => {
+                let value = args.next().ok_or(ArgsError::MissingOption("param"))?;
+                params.push(value.parse().map_err(ArgsError::InvalidOption)?);
+            }
+            "--input-dir" => {
+                input_dir = Some(
+                    args.next()
+                        .ok_or(ArgsError::MissingOption("input dir"))?
+                        .into(),
+                )
+            }
+            "--part" | "-p" => {
+                let value = args.next().ok_or(ArgsError::MissingOption("part"))?;
+                part = Some(value.parse::<Part>().map_err(ArgsError::InvalidOption)?);
+            }
+            "--format" => {
+                let value = args.next().ok_or(ArgsError::MissingOption("format"))?;
+                format = value.parse().map_err(ArgsError::InvalidOption)?;
+            }
+            "--all" => all = true,
+            "--profile" => profile = true,
+            "-v" | "--verbose" => verbosity += 1,
+            "-vv" => verbosity += 2,
+            "--input-str" if input.is_none() => {
+                input = Some(Input::Str(
+                    args.next().ok_or(ArgsError::MissingOption("input"))?,
+                ));
+            }
+            _ if input.is_none() && !arg.starts_with("--") => input = Some(Input::from_arg(&arg)),
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    let days = match (day, all) {
+        (Some(day), false) => Days::One(day),
+        (None, true) => Days::All,
+        (Some(_), true) => {
+            return Err(ArgsError::InvalidOption(String::from(
+                "--day and --all can't be used together.",
+            )))
+        }
+        (None, false) => return Err(ArgsError::MissingOption("--day <N> or --all")),
+    };
+
+    if days == Days::All && input.is_some() {
+        return Err(ArgsError::InvalidOption(String::from(
+            "An input can be passed only when running a single day.",
+        )));
+    }
+
+    if profile && format == Format::Json {
+        return Err(ArgsError::InvalidOption(String::from(
+            "--profile prints a table, it can't be used with --format json.",
+        )));
+    }
+
+    if let Some(jobs) = jobs {
+        if days != Days::All {
+            return Err(ArgsError::InvalidOption(String::from(
+                "--jobs can be used only with --all.",
+            )));
+        }
+
+        if jobs == 0 {
+            return Err(ArgsError::InvalidOption(String::from(
+                "--jobs has to be at least 1.",
+            )));
+        }
+
+        if profile || format == Format::Json {
+            return Err(ArgsError::InvalidOption(String::from(
+                "--jobs prints a summary table, it can't be used with --profile or --format json.",
+            )));
+        }
+    }
+
+    Ok(RunArgs {
+        days,
+        part,
+        input,
+        format,
+        profile,
+        verbosity,
+        jobs,
+        config,
+        params,
+        input_dir,
+    })
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, ArgsError> {
+    let mut verify_args = VerifyArgs {
+        day: None,
+        record: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => verify_args.day = Some(parse_day(args.next())?),
+            "--record" => verify_args.record = true,
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    Ok(verify_args)
+}
+
+fn parse_gen(mut args: impl Iterator<Item = String>) -> Result<GenArgs, ArgsError> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = aoc_gen::DEFAULT_SEED;
+    let mut marker_at = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(args.next())?),
+            "--size" => size = Some(parse_number(args.next(), "size")?),
+            "--seed" => seed = parse_number(args.next(), "seed")?,
+            "--marker-at" => marker_at = Some(parse_number(args.next(), "marker position")?),
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    let day = day.ok_or(ArgsError::MissingOption("--day <N>"))?;
+
+    if marker_at.is_some() && day != 6 {
+        return Err(ArgsError::InvalidOption(String::from(
+            "--marker-at can be used only with day 6.",
+        )));
+    }
+
+    Ok(GenArgs {
+        day,
+        size: size.ok_or(ArgsError::MissingOption("--size <N>"))?,
+        seed,
+        marker_at,
+    })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<NewArgs, ArgsError> {
+    let mut day = None;
+    let mut title = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" => title = Some(args.next().ok_or(ArgsError::MissingOption("title"))?),
+            _ if day.is_none() && !arg.starts_with("--") => day = Some(parse_day(Some(arg))?),
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    Ok(NewArgs {
+        day: day.ok_or(ArgsError::MissingOption("day"))?,
+        title: title.unwrap_or_else(|| String::from("Untitled")),
+    })
+}
+
+fn parse_fetch(mut args: impl Iterator<Item = String>) -> Result<FetchArgs, ArgsError> {
+    let mut fetch_args = FetchArgs {
+        day: None,
+        year: aoc_input::DEFAULT_YEAR,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => fetch_args.day = Some(parse_day(args.next())?),
+            "--year" => fetch_args.year = parse_number(args.next(), "year")?,
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    Ok(fetch_args)
+}
+
+fn parse_viz(mut args: impl Iterator<Item = String>) -> Result<VizArgs, ArgsError> {
+    let mut day = None;
+    let mut viz_args = VizArgs {
+        day: 0,
+        part: Part::A,
+        input: None,
+        fps: 10.0,
+        svg: None,
+        gif: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(args.next())?),
+            "--part" | "-p" => {
+                let value = args.next().ok_or(ArgsError::MissingOption("part"))?;
+                viz_args.part = value.parse::<Part>().map_err(ArgsError::InvalidOption)?;
+            }
+            "--fps" => viz_args.fps = parse_number(args.next(), "fps")?,
+            "--svg" => {
+                viz_args.svg = Some(args.next().ok_or(ArgsError::MissingOption("svg"))?.into())
+            }
+            "--gif" => {
+                viz_args.gif = Some(args.next().ok_or(ArgsError::MissingOption("gif"))?.into())
+            }
+            "--input-str" if viz_args.input.is_none() => {
+                viz_args.input = Some(Input::Str(
+                    args.next().ok_or(ArgsError::MissingOption("input"))?,
+                ));
+            }
+            _ if viz_args.input.is_none() && !arg.starts_with("--") => {
+                viz_args.input = Some(Input::from_arg(&arg))
+            }
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    if !viz_args.fps.is_finite() || viz_args.fps <= 0.0 {
+        return Err(ArgsError::InvalidOption(String::from(
+            "--fps has to be greater than 0.",
+        )));
+    }
+
+    Ok(VizArgs {
+        day: day.ok_or(ArgsError::MissingOption("--day <N>"))?,
+        ..viz_args
+    })
+}
+
+fn parse_serve(mut args: impl Iterator<Item = String>) -> Result<ServeArgs, ArgsError> {
+    let mut serve_args = ServeArgs {
+        host: String::from("127.0.0.1"),
+        port: 2022,
+        timeout: Duration::from_secs(10),
+        verbosity: 0,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--host" => serve_args.host = args.next().ok_or(ArgsError::MissingOption("host"))?,
+            "--port" => serve_args.port = parse_number(args.next(), "port")?,
+            "--timeout" => {
+                serve_args.timeout = Duration::from_millis(parse_number(args.next(), "timeout")?)
+            }
+            "-v" | "--verbose" => serve_args.verbosity += 1,
+            "-vv" => serve_args.verbosity += 2,
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    if serve_args.timeout.is_zero() {
+        return Err(ArgsError::InvalidOption(String::from(
+            "--timeout has to be at least 1 ms.",
+        )));
+    }
+
+    Ok(serve_args)
+}
+
+fn parse_watch(mut args: impl Iterator<Item = String>) -> Result<WatchArgs, ArgsError> {
+    let mut day = None;
+    let mut interval = Duration::from_millis(500);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(args.next())?),
+            "--interval" => {
+                interval = Duration::from_millis(parse_number(args.next(), "interval")?)
+            }
+            _ => return Err(ArgsError::UnexpectedArg(arg)),
+        }
+    }
+
+    if interval.is_zero() {
+        return Err(ArgsError::InvalidOption(String::from(
+            "--interval has to be at least 1 ms.",
+        )));
+    }
+
+    Ok(WatchArgs {
+        day: day.ok_or(ArgsError::MissingOption("--day <N>"))?,
+        interval,
+    })
+}
+
+fn parse_number<T: std::str::FromStr>(
+    value: Option<String>,
+    name: &'static str,
+) -> Result<T, ArgsError> {
+    let value = value.ok_or(ArgsError::MissingOption(name))?;
+
+    value
+        .parse::<T>()
+        .map_err(|_| ArgsError::InvalidOption(format!("Invalid {}: \"{}\".", name, value)))
+}
+
+fn parse_day(value: Option<String>) -> Result<u8, ArgsError> {
+    let value = value.ok_or(ArgsError::MissingOption("day"))?;
+
+    value
+        .parse::<u8>()
+        .map_err(|_| ArgsError::InvalidOption(format!("Invalid day: \"{}\".", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Command, Days, FetchArgs, GenArgs, NewArgs, RunArgs, ServeArgs, VerifyArgs, VizArgs,
+        WatchArgs,
+    };
+    use aoc_common::{Format, Input, Part};
+    use rstest::rstest;
+    use std::time::Duration;
+
+    fn parse(args: &[&str]) -> Result<Command, aoc_common::ArgsError> {
+        Command::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[rstest]
+    #[case(&["run", "--day", "5", "--part", "B", "input.txt"], Days::One(5), Some(Part::B), Some(Input::File("input.txt".into())))]
+    #[case(&["run", "-d", "3"], Days::One(3), None, None)]
+    #[case(&["run", "--all"], Days::All, None, None)]
+    #[case(&["run", "--all", "--part", "A"], Days::All, Some(Part::A), None)]
+    #[case(&["run", "-d", "2", "-"], Days::One(2), None, Some(Input::Stdin))]
+    #[case(&["run", "-d", "2", "--input-str", "A Y"], Days::One(2), None, Some(Input::Str("A Y".into())))]
+    fn should_parse_run(
+        #[case] args: &[&str],
+        #[case] days: Days,
+        #[case] part: Option<Part>,
+        #[case] input: Option<Input>,
+    ) {
+        assert_eq!(
+            Command::Run(RunArgs {
+                days,
+                part,
+                input,
+                format: Format::Text,
+                profile: false,
+                verbosity: 0,
+                jobs: None,
+                config: None,
+                params: Vec::new(),
+                input_dir: None,
+            }),
+            parse(args).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_parse_format() {
+        match parse(&["run", "--all", "--format", "json"]).unwrap() {
+            Command::Run(args) => assert_eq!(Format::Json, args.format),
+            command => panic!("Unexpected command: {:?}", command),
+        }
+    }
+
+    #[rstest]
+    #[case(&["run", "--all", "-v"], 1)]
+    #[case(&["run", "-vv", "--day", "5"], 2)]
+    #[case(&["run", "-d", "5", "--verbose", "-v", "-"], 2)]
+    fn should_parse_verbosity(#[case] args: &[&str], #[case] expected: u8) {
+        match parse(args).unwrap() {
+            Command::Run(args) => assert_eq!(expected, args.verbosity),
+            command => panic!("Unexpected command: {:?}", command),
+        }
+    }
+
+    #[test]
+    fn should_parse_profile() {
+        match parse(&["run", "--all", "--profile"]).unwrap() {
+            Command::Run(args) => assert!(args.profile),
+            command => panic!("Unexpected command: {:?}", command),
+        }
+    }
+
+    #[rstest]
+    #[case(&["run", "--all", "--jobs", "4"], 4)]
+    #[case(&["run", "-j", "1", "--all", "--part", "B"], 1)]
+    fn should_parse_jobs(#[case] args: &[&str], #[case] expected: usize) {
+        match parse(args).unwrap() {
+            Command::Run(args) => assert_eq!(Some(expected), args.jobs),
+            command => panic!("Unexpected command: {:?}", command),
+        }
+    }
+
+    #[test]
+    fn should_parse_config_and_params() {
+        match parse(&[
+            "run",
+            "--day",
+            "7",
+            "--config",
+            "variant.toml",
+            "--param",
+            "disk_size=80000000",
+            "--param",
+            "day_7.required_space=1",
+            "--input-dir",
+            "inputs",
+        ])
+        .unwrap()
+        {
+            Command::Run(args) => {
+                assert_eq!(Some("variant.toml".into()), args.config);
+                assert_eq!(
+                    vec!["disk_size=80000000", "day_7.required_space=1"],
+                    args.params
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                );
+                assert_eq!(Some("inputs".into()), args.input_dir);
+            }
+            command => panic!("Unexpected command: {:?}", command),
+        }
+    }
+
+    #[rstest]
+    #[case(&["run"])]
+    #[case(&["run", "--day"])]
+    #[case(&["run", "--day", "x"])]
+    #[case(&["run", "--day", "1", "--all"])]
+    #[case(&["run", "--all", "input.txt"])]
+    #[case(&["run", "--day", "1", "--part", "C"])]
+    #[case(&["run", "--day", "1", "a.txt", "b.txt"])]
+    #[case(&["run", "--day", "1", "a.txt", "--input-str", "1"])]
+    #[case(&["run", "--day", "1", "--input-str"])]
+    #[case(&["run", "--day", "1", "--format", "xml"])]
+    #[case(&["run", "--day", "1", "--format", "json", "--profile"])]
+    #[case(&["run", "--day", "1", "--jobs", "2"])]
+    #[case(&["run", "--all", "--jobs", "0"])]
+    #[case(&["run", "--all", "--jobs"])]
+    #[case(&["run", "--all", "--jobs", "2", "--profile"])]
+    #[case(&["run", "--all", "--jobs", "2", "--format", "json"])]
+    #[case(&["run", "--all", "--param", "top"])]
+    #[case(&["run", "--all", "--param"])]
+    #[case(&["run", "--all", "--config"])]
+    #[case(&["run", "--all", "--input-dir"])]
+    #[case(&["fly"])]
+    fn should_fail_parsing(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[rstest]
+    #[case(&["verify"], None, false)]
+    #[case(&["verify", "--day", "4"], Some(4), false)]
+    #[case(&["verify", "--record"], None, true)]
+    fn should_parse_verify(#[case] args: &[&str], #[case] day: Option<u8>, #[case] record: bool) {
+        assert_eq!(
+            Command::Verify(VerifyArgs { day, record }),
+            parse(args).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["verify", "--day"])]
+    #[case(&["verify", "input.txt"])]
+    fn should_fail_parsing_verify(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[rstest]
+    #[case(&["gen", "--day", "3", "--size", "1000"], 3, 1000, aoc_gen::DEFAULT_SEED, None)]
+    #[case(&["gen", "-d", "6", "--size", "100", "--seed", "7", "--marker-at", "50"], 6, 100, 7, Some(50))]
+    fn should_parse_gen(
+        #[case] args: &[&str],
+        #[case] day: u8,
+        #[case] size: usize,
+        #[case] seed: u64,
+        #[case] marker_at: Option<usize>,
+    ) {
+        assert_eq!(
+            Command::Gen(GenArgs {
+                day,
+                size,
+                seed,
+                marker_at
+            }),
+            parse(args).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["gen", "--size", "10"])]
+    #[case(&["gen", "--day", "1"])]
+    #[case(&["gen", "--day", "1", "--size", "-1"])]
+    #[case(&["gen", "--day", "1", "--size", "10", "--marker-at", "5"])]
+    #[case(&["gen", "--day", "6", "--size", "10", "--seed"])]
+    fn should_fail_parsing_gen(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[rstest]
+    #[case(&["new", "8"], 8, "Untitled")]
+    #[case(&["new", "8", "--title", "Treetop Tree House"], 8, "Treetop Tree House")]
+    #[case(&["new", "--title", "Treetop Tree House", "8"], 8, "Treetop Tree House")]
+    fn should_parse_new(#[case] args: &[&str], #[case] day: u8, #[case] title: &str) {
+        assert_eq!(
+            Command::New(NewArgs {
+                day,
+                title: title.to_string()
+            }),
+            parse(args).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["new"])]
+    #[case(&["new", "x"])]
+    #[case(&["new", "8", "9"])]
+    #[case(&["new", "8", "--title"])]
+    fn should_fail_parsing_new(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[rstest]
+    #[case(&["fetch"], None, 2022)]
+    #[case(&["fetch", "--day", "6"], Some(6), 2022)]
+    #[case(&["fetch", "-d", "1", "--year", "2021"], Some(1), 2021)]
+    fn should_parse_fetch(#[case] args: &[&str], #[case] day: Option<u8>, #[case] year: u16) {
+        assert_eq!(
+            Command::Fetch(FetchArgs { day, year }),
+            parse(args).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["fetch", "--year"])]
+    #[case(&["fetch", "--year", "twenty"])]
+    #[case(&["fetch", "6"])]
+    fn should_fail_parsing_fetch(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[test]
+    fn should_show_help() {
+        assert_eq!(Command::Help, parse(&[]).unwrap());
+    }
+
+    #[test]
+    fn should_parse_viz() {
+        assert_eq!(
+            Command::Viz(VizArgs {
+                day: 5,
+                part: Part::B,
+                input: Some(Input::Stdin),
+                fps: 2.5,
+                svg: None,
+                gif: Some("stacks.gif".into()),
+            }),
+            parse(&[
+                "viz",
+                "-d",
+                "5",
+                "--part",
+                "B",
+                "--fps",
+                "2.5",
+                "--gif",
+                "stacks.gif",
+                "-"
+            ])
+            .unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["viz"])]
+    #[case(&["viz", "--day", "4", "--fps", "0"])]
+    #[case(&["viz", "--day", "4", "--fps", "fast"])]
+    #[case(&["viz", "--day", "4", "--svg"])]
+    fn should_fail_parsing_viz(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[rstest]
+    #[case(&["serve"], "127.0.0.1", 2022, 10000, 0)]
+    #[case(&["serve", "--port", "0", "-v"], "127.0.0.1", 0, 10000, 1)]
+    #[case(&["serve", "--host", "0.0.0.0", "--port", "8080"], "0.0.0.0", 8080, 10000, 0)]
+    #[case(&["serve", "--timeout", "500"], "127.0.0.1", 2022, 500, 0)]
+    fn should_parse_serve(
+        #[case] args: &[&str],
+        #[case] host: &str,
+        #[case] port: u16,
+        #[case] timeout: u64,
+        #[case] verbosity: u8,
+    ) {
+        assert_eq!(
+            Command::Serve(ServeArgs {
+                host: host.to_string(),
+                port,
+                timeout: Duration::from_millis(timeout),
+                verbosity
+            }),
+            parse(args).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["serve", "--port"])]
+    #[case(&["serve", "--port", "65536"])]
+    #[case(&["serve", "--host"])]
+    #[case(&["serve", "8080"])]
+    #[case(&["serve", "--timeout", "0"])]
+    fn should_fail_parsing_serve(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[rstest]
+    #[case(&["watch", "--day", "5"], 5, 500)]
+    #[case(&["watch", "--interval", "100", "-d", "1"], 1, 100)]
+    fn should_parse_watch(#[case] args: &[&str], #[case] day: u8, #[case] interval: u64) {
+        assert_eq!(
+            Command::Watch(WatchArgs {
+                day,
+                interval: Duration::from_millis(interval)
+            }),
+            parse(args).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(&["watch"])]
+    #[case(&["watch", "--day", "5", "--interval", "0"])]
+    #[case(&["watch", "--day", "5", "--interval", "fast"])]
+    #[case(&["watch", "5"])]
+    fn should_fail_parsing_watch(#[case] args: &[&str]) {
+        assert!(parse(args).is_err());
+    }
+
+    #[rstest]
+    #[case(&["readme"], true)]
+    #[case(&["readme", "--check"], false)]
+    fn should_parse_readme(#[case] args: &[&str], #[case] valid: bool) {
+        assert_eq!(valid, parse(args) == Ok(Command::Readme));
+    }
+}
@@ -0,0 +1,609 @@
+mod answers;
+mod cli;
+mod pool;
+mod readme;
+mod registry;
+mod scaffold;
+mod serve;
+mod summary;
+mod verify;
+mod watch;
+
+use answers::Answers;
+use aoc_common::{
+    config::Config, measure, CountingAllocator, Entry, Error, Format, Input, Params, Part, Report,
+};
+use aoc_input::{HttpFetcher, InputCache, InputManager, Source};
+use cli::{
+    Command, Days, FetchArgs, GenArgs, NewArgs, RunArgs, ServeArgs, VerifyArgs, VizArgs, WatchArgs,
+};
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    net::TcpListener,
+    process, thread,
+    time::{Duration, Instant},
+};
+
+/// Counts allocations, reported by `aoc run --profile`.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+    let command = Command::parse(std::env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!("{}", cli::USAGE);
+        process::exit(2);
+    });
+
+    let succeeded = match command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Gen(args) => gen(args),
+        Command::New(args) => new(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Viz(args) => viz(args),
+        Command::Serve(args) => serve(args),
+        Command::Watch(args) => watch(args),
+        Command::Readme => readme(),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            true
+        }
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+/// What a day is run with: command line options, else config ones (see [`aoc_common::config`]), else defaults.
+struct Setup {
+    input: Input,
+    parts: Vec<Part>,
+    params: Params,
+}
+
+impl Setup {
+    fn new(entry: &Entry, args: &RunArgs, config: &Config) -> Result<Self, String> {
+        let input = args
+            .input
+            .clone()
+            .or_else(|| {
+                args.input_dir
+                    .as_ref()
+                    .map(|dir| Input::File(dir.join(format!("day_{}.txt", entry.day))))
+            })
+            .unwrap_or_else(|| registry::configured_input(config, entry.day));
+        let parts = match args.part.or_else(|| config.part(entry.day)) {
+            Some(part) => vec![part],
+            None => vec![Part::A, Part::B],
+        };
+        let params = config
+            .params(entry.day, entry.params)
+            .map_err(|err| err.render())?
+            .with_overrides(entry.day, entry.params, &args.params)?;
+
+        Ok(Self {
+            input,
+            parts,
+            params,
+        })
+    }
+}
+
+/// Run requested days and parts, returns false if at least one of them failed.
+fn run(args: RunArgs) -> bool {
+    aoc_common::init_tracing(args.verbosity);
+
+    let entries: Vec<&Entry> = match args.days {
+        Days::One(day) => match registry::find(day) {
+            Some(entry) => vec![entry],
+            None => {
+                eprintln!("Day {} is not solved yet.", day);
+                return false;
+            }
+        },
+        Days::All => registry::DAYS.iter().collect(),
+    };
+
+    let config = match registry::load_config(args.config.as_deref()) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err.render());
+            return false;
+        }
+    };
+
+    let unused = args.params.iter().find(|param| {
+        !entries.iter().any(|entry| match param.day {
+            Some(day) => day == entry.day,
+            None => entry.params.iter().any(|p| p.name == param.name),
+        })
+    });
+    if let Some(param) = unused {
+        eprintln!("No day to run has parameter: {}.", param);
+        return false;
+    }
+
+    let setups = match entries
+        .iter()
+        .map(|entry| Setup::new(entry, &args, &config))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(setups) => setups,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+
+    if let Some(jobs) = args.jobs {
+        return run_in_parallel(&entries, &setups, jobs);
+    }
+
+    let mut succeeded = true;
+
+    for (entry, setup) in entries.iter().zip(&setups) {
+        let input = &setup.input;
+
+        if args.profile {
+            succeeded &= profile(entry, setup);
+            continue;
+        }
+
+        // Read once, stdin can't be read again for the next part.
+        let content = input.read_to_string().map_err(Error::from);
+
+        for part in &setup.parts {
+            let answer = content
+                .as_deref()
+                .map_err(Clone::clone)
+                .and_then(|content| solve(entry, input, content, *part, &setup.params));
+
+            match answer {
+                Ok((answer, elapsed)) => match args.format {
+                    Format::Text => println!(
+                        "Day {} ({}) part {}: {}",
+                        entry.day, entry.title, part, answer
+                    ),
+                    Format::Json => {
+                        let report = Report {
+                            day: entry.day,
+                            part: part.to_string(),
+                            answer,
+                            elapsed,
+                            input,
+                        };
+                        println!("{}", report.to_json());
+                    }
+                },
+                Err(err) => {
+                    eprintln!("Day {} ({}) part {} failed:", entry.day, entry.title, part);
+                    eprintln!("{}", err.render());
+                    succeeded = false;
+                }
+            }
+        }
+    }
+
+    succeeded
+}
+
+/// Solve and verify every part of days on `jobs` threads then print a summary table,
+/// returns false if at least one of them failed. A panicking day doesn't stop the others.
+fn run_in_parallel(entries: &[&Entry], setups: &[Setup], jobs: usize) -> bool {
+    let answers = Answers::load(&registry::answers_path()).unwrap_or_else(|err| err.exit());
+    let tasks = entries
+        .iter()
+        .zip(setups)
+        .flat_map(|(entry, setup)| setup.parts.iter().map(move |part| (*entry, setup, *part)))
+        .collect::<Vec<_>>();
+
+    let outcomes = pool::run(&tasks, jobs, |(entry, setup, part)| {
+        verify::verify(entry, &setup.input, *part, &setup.params, &answers)
+    });
+
+    println!("{}", summary::table(&outcomes));
+
+    for outcome in &outcomes {
+        if let verify::Status::Error(err) = &outcome.status {
+            eprintln!("Day {} part {} failed:", outcome.day, outcome.part);
+            eprintln!("{}", err.render());
+        }
+    }
+
+    outcomes.iter().all(|outcome| !outcome.is_failure())
+}
+
+/// Solve parts of a day printing answers and where time and memory go, returns false if it failed.
+fn profile(entry: &Entry, setup: &Setup) -> bool {
+    let (content, read) = measure("read", || setup.input.read_to_string());
+    let profile = content
+        .map_err(Error::from)
+        .and_then(|content| (entry.profile)(&content, &setup.parts, &setup.params))
+        .map_err(|err| err.with_file(setup.input.to_string()));
+
+    match profile {
+        Ok(mut profile) => {
+            for (part, answer) in &profile.answers {
+                println!(
+                    "Day {} ({}) part {}: {}",
+                    entry.day, entry.title, part, answer
+                );
+            }
+            profile.phases.insert(0, read);
+            println!("\n{}", profile.table());
+            true
+        }
+        Err(err) => {
+            eprintln!("Day {} ({}) failed:", entry.day, entry.title);
+            eprintln!("{}", err.render());
+            false
+        }
+    }
+}
+
+/// Solve a day part, returns answer and time spent on it.
+fn solve(
+    entry: &Entry,
+    input: &Input,
+    content: &str,
+    part: Part,
+    params: &Params,
+) -> Result<(String, Duration), Error> {
+    let start = Instant::now();
+
+    (entry.solve)(&mut content.as_bytes(), part, params)
+        .map(|answer| (answer, start.elapsed()))
+        .map_err(|err| err.with_file(input.to_string()))
+}
+
+/// Verify answers of requested days against the registry, returns false if at least one of them failed.
+fn verify(args: VerifyArgs) -> bool {
+    let path = registry::answers_path();
+    let mut answers = Answers::load(&path).unwrap_or_else(|err| err.exit());
+
+    let entries: Vec<&Entry> = match args.day {
+        Some(day) => match registry::find(day) {
+            Some(entry) => vec![entry],
+            None => {
+                eprintln!("Day {} is not solved yet.", day);
+                return false;
+            }
+        },
+        None => registry::DAYS.iter().collect(),
+    };
+
+    let config = registry::load_config(None).unwrap_or_else(|err| err.exit());
+    let mut outcomes = Vec::new();
+
+    for entry in entries {
+        let input = registry::configured_input(&config, entry.day);
+
+        for part in [Part::A, Part::B] {
+            let outcome = verify::verify(entry, &input, part, &entry.default_params(), &answers);
+            println!("{}", outcome);
+            outcomes.push(outcome);
+        }
+    }
+
+    let failed = outcomes.iter().filter(|o| o.is_failure()).count();
+    let missing = outcomes
+        .iter()
+        .filter_map(|o| o.to_record())
+        .collect::<Vec<_>>();
+
+    let count = |status| outcomes.iter().filter(|o| o.status == status).count();
+    println!(
+        "{} passed, {} failed, {} missing, {} skipped",
+        count(verify::Status::Pass),
+        failed,
+        missing.len(),
+        count(verify::Status::NoInput)
+    );
+
+    if args.record && !missing.is_empty() {
+        let recorded = missing.len();
+
+        for answer in missing {
+            answers.add(answer);
+        }
+
+        answers
+            .save(&path)
+            .unwrap_or_else(|err| Error::from(err).exit());
+        println!("Recorded {} answers in: {}", recorded, path.display());
+    }
+
+    failed == 0
+}
+
+/// Print a synthetic input, returns false if it can't be generated.
+fn gen(args: GenArgs) -> bool {
+    let input = match args.marker_at {
+        Some(position) => aoc_gen::Generator::new(args.seed).signal(
+            args.size,
+            aoc_gen::SIGNAL_MARKER_LENGTH,
+            position,
+        ),
+        None => aoc_gen::generate(args.day, args.size, args.seed)
+            .ok_or_else(|| format!("Day {} has no generator.", args.day)),
+    };
+
+    match input {
+        Ok(input) => {
+            print!("{}", input);
+            true
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+/// Scaffold next day crate, returns false if it can't be created.
+fn new(args: NewArgs) -> bool {
+    // Runner registry lists days in order, with no gaps.
+    let next = registry::DAYS.len() as u8 + 1;
+
+    if args.day != next {
+        eprintln!(
+            "Days are added in order, next day is: {}, got: {}.",
+            next, args.day
+        );
+        return false;
+    }
+
+    let root = registry::workspace_root();
+
+    match scaffold::scaffold(root, args.day, &args.title) {
+        Ok(files) => {
+            for file in files {
+                println!("Written: {}", file.display());
+            }
+            if !matches!(scaffold::regenerate_readme(root), Ok(true)) {
+                eprintln!("Unable to update README, once the day builds: cargo run --release -p aoc -- readme");
+            }
+            println!(
+                "Paste puzzle input in: day_{}/src/input.txt, then: cargo run -p aoc -- run --day {}",
+                args.day, args.day
+            );
+            true
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+/// Fetch missing inputs of requested days into input cache, returns false if at least one of them failed.
+fn fetch(args: FetchArgs) -> bool {
+    let cache = match InputCache::from_env() {
+        Some(cache) => cache,
+        None => {
+            eprintln!("Unable to find input cache, set: AOC_CACHE_DIR.");
+            return false;
+        }
+    };
+    let fetcher = match HttpFetcher::from_env() {
+        Ok(fetcher) => fetcher,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+    let manager = InputManager::new(cache, fetcher);
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => registry::DAYS.iter().map(|entry| entry.day).collect(),
+    };
+    let mut succeeded = true;
+
+    for day in days {
+        let path = manager.cache().path(args.year, day);
+
+        match manager.get(args.year, day) {
+            Ok((_, Source::Cache)) => {
+                println!("Day {}: already cached in: {}", day, path.display())
+            }
+            Ok((_, Source::Fetched)) => println!("Day {}: fetched in: {}", day, path.display()),
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                succeeded = false;
+            }
+        }
+    }
+
+    succeeded
+}
+
+/// Draw a day part: play frames in terminal, or export them as svg frames or gif.
+/// Returns false if day can't be drawn.
+fn viz(args: VizArgs) -> bool {
+    let visualize = match registry::find_visualization(args.day) {
+        Some(visualize) => visualize,
+        None => {
+            eprintln!("Day {} has no visualization.", args.day);
+            return false;
+        }
+    };
+    let input = match args.input {
+        Some(input) => input,
+        None => {
+            let config = registry::load_config(None).unwrap_or_else(|err| err.exit());
+            registry::configured_input(&config, args.day)
+        }
+    };
+    let frames = match input
+        .read_to_string()
+        .map_err(Error::from)
+        .and_then(|content| visualize(&content, args.part))
+    {
+        Ok(frames) => frames,
+        Err(err) => {
+            eprintln!("{}", err.with_file(input.to_string()).render());
+            return false;
+        }
+    };
+    let delay = aoc_viz::ansi::frame_delay(args.fps);
+
+    let written = match (&args.svg, &args.gif) {
+        (None, None) if io::stdout().is_terminal() => {
+            aoc_viz::ansi::play(&frames, &mut io::stdout(), args.fps).map(|_| Vec::new())
+        }
+        // Piped: frames one after the other, without colors.
+        (None, None) => {
+            for frame in &frames {
+                println!("{}\n", frame.text());
+            }
+            Ok(Vec::new())
+        }
+        (svg, gif) => svg
+            .iter()
+            .map(|dir| aoc_viz::svg::write_frames(&frames, dir))
+            .chain(gif.iter().map(|path| {
+                fs::write(path, aoc_viz::gif::encode(&frames, delay)).map(|_| vec![path.clone()])
+            }))
+            .collect::<io::Result<Vec<_>>>()
+            .map(|files| files.concat()),
+    };
+
+    match written {
+        Ok(files) => {
+            if !files.is_empty() {
+                println!("Written {} frames in {} files.", frames.len(), files.len());
+            }
+            true
+        }
+        Err(err) => {
+            eprintln!("Unable to draw day {}: {}", args.day, err);
+            false
+        }
+    }
+}
+
+/// Solve inputs posted over http until the server fails, returns false when it does.
+fn serve(args: ServeArgs) -> bool {
+    aoc_common::init_tracing(args.verbosity);
+
+    let listener = match TcpListener::bind((args.host.as_str(), args.port)) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("Unable to listen on {}:{}: {}", args.host, args.port, err);
+            return false;
+        }
+    };
+
+    match listener.local_addr() {
+        Ok(address) => println!("Listening on: http://{}", address),
+        Err(err) => {
+            eprintln!("Unable to listen: {}", err);
+            return false;
+        }
+    }
+
+    if let Err(err) = serve::serve(listener, args.timeout) {
+        eprintln!("Server stopped: {}", err);
+        return false;
+    }
+
+    true
+}
+
+/// Solve a day and run its tests each time its sources or inputs change, until interrupted.
+/// Returns false if files can't be watched.
+fn watch(args: WatchArgs) -> bool {
+    let entry = match registry::find(args.day) {
+        Some(entry) => entry,
+        None => {
+            eprintln!("Day {} is not solved yet.", args.day);
+            return false;
+        }
+    };
+    let root = registry::workspace_root();
+    let day_dir = root.join(format!("day_{}", entry.day));
+    let dirs = [day_dir.join("src")];
+    // Runs are solved by `aoc run`, with the input of the config, that may come from input cache, out of day dir.
+    let config = registry::load_config(None).unwrap_or_else(|err| err.exit());
+    let files = [
+        day_dir.join("Cargo.toml"),
+        registry::default_input_path(entry.day),
+    ]
+    .into_iter()
+    .chain(registry::default_config_path())
+    .chain(match registry::configured_input(&config, entry.day) {
+        Input::File(path) => Some(path),
+        _ => None,
+    })
+    .collect::<Vec<_>>();
+
+    let mut previous: Option<watch::Run> = None;
+    let mut snapshot = watch::Snapshot::default();
+
+    loop {
+        let changed = match watch::Snapshot::take(&dirs, &files) {
+            Ok(next) => {
+                let changed = next.changed(&snapshot);
+                snapshot = next;
+                changed
+            }
+            Err(err) => {
+                eprintln!("Unable to watch: {}: {}", day_dir.display(), err);
+                return false;
+            }
+        };
+
+        if changed.is_empty() {
+            thread::sleep(args.interval);
+            continue;
+        }
+
+        if previous.is_some() {
+            for path in &changed {
+                println!(
+                    "Changed: {}",
+                    path.strip_prefix(root).unwrap_or(path).display()
+                );
+            }
+        }
+        println!("Day {} ({}):", entry.day, entry.title);
+
+        match watch::run(root, entry.day) {
+            Ok(run) => {
+                println!("{}\n", watch::report(previous.as_ref(), &run));
+                previous = Some(run);
+            }
+            Err(err) => {
+                eprintln!("Unable to run cargo: {}", err);
+                return false;
+            }
+        }
+    }
+}
+
+/// Regenerate the days section of the workspace README, returns false if it can't be written.
+fn readme() -> bool {
+    let answers = Answers::load(&registry::answers_path()).unwrap_or_else(|err| err.exit());
+    let config = registry::load_config(None).unwrap_or_else(|err| err.exit());
+    let days = readme::verify_days(&config, &answers);
+
+    let path = registry::workspace_root().join("README.md");
+    let written = fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|content| readme::replace(&content, &readme::section(&days)))
+        .and_then(|content| fs::write(&path, content).map_err(|err| err.to_string()));
+
+    match written {
+        Ok(()) => {
+            println!("Written: {}", path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("Unable to generate {}: {}", path.display(), err);
+            false
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Run `job` on each task with `jobs` threads taking the next task as soon as they are done.
+/// Results are in the same order as tasks.
+pub fn run<T, R>(tasks: &[T], jobs: usize, job: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..tasks.len()).map(|_| None).collect::<Vec<Option<R>>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+
+                let Some(task) = tasks.get(idx) else {
+                    break;
+                };
+                let result = job(task);
+
+                results.lock().unwrap()[idx] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("Every task is run by a thread."))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use rstest::rstest;
+    use std::{
+        collections::HashSet,
+        sync::Mutex,
+        thread::{self, ThreadId},
+        time::Duration,
+    };
+
+    #[rstest]
+    #[case(1)]
+    #[case(3)]
+    #[case(64)]
+    fn should_keep_tasks_order(#[case] jobs: usize) {
+        let tasks = (0..20u64).collect::<Vec<_>>();
+        let results = run(&tasks, jobs, |task| {
+            // Late tasks end first.
+            thread::sleep(Duration::from_millis(20 - task));
+            task * 2
+        });
+
+        assert_eq!(tasks.iter().map(|t| t * 2).collect::<Vec<_>>(), results);
+    }
+
+    #[test]
+    fn should_use_at_most_jobs_threads() {
+        let threads = Mutex::new(HashSet::<ThreadId>::new());
+
+        run(&[0; 16], 2, |_| {
+            threads.lock().unwrap().insert(thread::current().id());
+            thread::sleep(Duration::from_millis(5));
+        });
+
+        assert!(threads.into_inner().unwrap().len() <= 2);
+    }
+
+    #[test]
+    fn should_run_no_tasks() {
+        assert!(run(&[] as &[u8], 4, |t| *t).is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! `aoc readme`: days section of the workspace README, generated from the registry so that it can't drift from the binaries.
+
+use aoc_common::{config::Config, Entry, Part};
+use std::fmt::Write;
+
+use crate::{
+    answers::Answers,
+    registry, summary,
+    verify::{self, Outcome},
+};
+
+/// Generated section lives between these lines, the rest of the README is hand-written.
+pub const START: &str =
+    "<!-- aoc readme: start, generated by `cargo run --release -p aoc -- readme` -->";
+pub const END: &str = "<!-- aoc readme: end -->";
+
+/// Table of days with verification status of each part, followed by the usage of every day binary.
+/// Nothing depends on the machine it runs on, so regenerating it gives the same text until a day changes.
+pub fn section(days: &[(&Entry, Vec<Outcome>)]) -> String {
+    let entries = days.iter().map(|(entry, _)| *entry).collect::<Vec<_>>();
+
+    format!(
+        "{}\n{}\nUsage of day binaries, run with `cargo run -p day_<N> --`:\n\n```text\n{}```\n{}",
+        START,
+        table(days),
+        usages(&entries),
+        END
+    )
+}
+
+/// Outcomes of both parts of every day, on the input of `config`.
+pub fn verify_days(config: &Config, answers: &Answers) -> Vec<(&'static Entry, Vec<Outcome>)> {
+    registry::DAYS
+        .iter()
+        .map(|entry| {
+            let input = registry::configured_input(config, entry.day);
+            let outcomes = [Part::A, Part::B]
+                .into_iter()
+                .map(|part| verify::verify(entry, &input, part, &entry.default_params(), answers))
+                .collect::<Vec<_>>();
+            (entry, outcomes)
+        })
+        .collect()
+}
+
+/// Markdown table with a row per day, parts are verified against `answers.txt`.
+pub fn table(days: &[(&Entry, Vec<Outcome>)]) -> String {
+    let rows = days
+        .iter()
+        .map(|(entry, outcomes)| {
+            let part = |idx: usize| outcomes.get(idx).map(summary::status).unwrap_or_default();
+
+            vec![
+                entry.day.to_string(),
+                format!(
+                    "[{}](https://adventofcode.com/2022/day/{})",
+                    entry.title, entry.day
+                ),
+                format!("[day_{0}](day_{0}/README.md)", entry.day),
+                part(0),
+                part(1),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    summary::markdown(&["day", "puzzle", "crate", "part A", "part B"], &rows, &[0])
+}
+
+/// Usage line of each day binary, followed by its parameters. Eg:
+/// ```text
+/// day_7 <input path | - | --input-str <input>> <puzzle option (A | B)> [--format <text | json>] [--param <name>=<N>]... [-v | -vv]
+///     --param disk_size=<N>  Size of the disk, in part B. Default: 70000000, at least: 1.
+/// ```
+pub fn usages(entries: &[&Entry]) -> String {
+    let mut usages = String::new();
+
+    for entry in entries {
+        writeln!(
+            usages,
+            "day_{} {}",
+            entry.day,
+            aoc_common::usage(entry.option)
+        )
+        .unwrap();
+
+        let width = entry
+            .params
+            .iter()
+            .map(|param| param.name.len())
+            .max()
+            .unwrap_or(0);
+
+        for param in entry.params {
+            write!(
+                usages,
+                "    --param {:<width$}  {} Default: {}",
+                format!("{}=<N>", param.name),
+                param.about,
+                param.default,
+                width = width + 4
+            )
+            .unwrap();
+
+            match param.min {
+                0 => writeln!(usages, "."),
+                min => writeln!(usages, ", at least: {}.", min),
+            }
+            .unwrap();
+        }
+    }
+
+    usages
+}
+
+/// `readme` with its generated section replaced by `section`.
+pub fn replace(readme: &str, section: &str) -> Result<String, String> {
+    let start = readme.find(START);
+    let end = readme.find(END);
+
+    match (start, end) {
+        (Some(start), Some(end)) if start < end => Ok(format!(
+            "{}{}{}",
+            &readme[..start],
+            section,
+            &readme[end + END.len()..]
+        )),
+        _ => Err(format!(
+            "README has no generated section, add lines:\n{}\n{}",
+            START, END
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replace, section, table, usages, verify_days, END, START};
+    use crate::{
+        answers::Answers,
+        registry,
+        verify::{Outcome, Status},
+    };
+    use aoc_common::Part;
+    use std::{fs, time::Duration};
+
+    fn outcome(day: u8, part: Part, status: Status) -> Outcome {
+        Outcome {
+            day,
+            part,
+            input_hash: String::new(),
+            answer: None,
+            elapsed: Duration::from_micros(1500),
+            status,
+        }
+    }
+
+    #[test]
+    fn should_render_a_row_per_day() {
+        let day_1 = registry::find(1).unwrap();
+        let day_7 = registry::find(7).unwrap();
+        let table = table(&[
+            (
+                day_1,
+                vec![
+                    outcome(1, Part::A, Status::Pass),
+                    outcome(1, Part::B, Status::Missing),
+                ],
+            ),
+            (
+                day_7,
+                vec![
+                    outcome(7, Part::A, Status::NoInput),
+                    outcome(7, Part::B, Status::NoInput),
+                ],
+            ),
+        ]);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(4, lines.len());
+        assert_eq!(
+            "|   1 | [Calorie Counting](https://adventofcode.com/2022/day/1)        | [day_1](day_1/README.md) | pass     | missing  |",
+            lines[2]
+        );
+        assert_eq!(
+            "|   7 | [No Space Left On Device](https://adventofcode.com/2022/day/7) | [day_7](day_7/README.md) | no input | no input |",
+            lines[3]
+        );
+    }
+
+    #[test]
+    fn should_list_params_under_usage() {
+        let usages = usages(&[registry::find(6).unwrap(), registry::find(7).unwrap()]);
+        let lines = usages.lines().collect::<Vec<_>>();
+
+        assert_eq!(5, lines.len());
+        assert!(lines[0]
+            .starts_with("day_6 <input path | - | --input-str <input>> <marker length, usize> "));
+        assert_eq!(
+            "    --param disk_size=<N>       Size of the disk, in part B. Default: 70000000, at least: 1.",
+            lines[3]
+        );
+        assert_eq!(
+            "    --param max_dir_size=<N>    Part A sums dirs of at most this size. Default: 100000.",
+            lines[2]
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("# Title\nintro\n", None)]
+    #[case(&format!("# Title\n{}\nold\n{}\nrest\n", END, START), None)]
+    #[case(&format!("# Title\n{}\nold\n{}\nrest\n", START, END), Some("# Title\nnew\nrest\n"))]
+    fn should_replace_generated_section(#[case] readme: &str, #[case] expected: Option<&str>) {
+        assert_eq!(expected.map(str::to_string), replace(readme, "new").ok());
+    }
+
+    #[test]
+    fn should_document_every_day() {
+        let readme = fs::read_to_string(registry::workspace_root().join("README.md")).unwrap();
+        let answers = Answers::load(&registry::answers_path()).unwrap();
+        let days = verify_days(&registry::load_config(None).unwrap(), &answers);
+
+        assert!(
+            readme.contains(&section(&days)),
+            "README is out of date, run: cargo run --release -p aoc -- readme"
+        );
+        assert!(section(&[]).starts_with(START));
+    }
+}
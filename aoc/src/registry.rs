@@ -0,0 +1,138 @@
+use aoc_common::{config::Config, Entry, Error, Input, Part};
+use aoc_input::InputCache;
+use aoc_viz::Frame;
+use std::path::{Path, PathBuf};
+
+/// Every day solved so far.
+pub const DAYS: &[Entry] = &[
+    Entry::new::<day_1::Puzzle>(),
+    Entry::new::<day_2::Puzzle>(),
+    Entry::new::<day_3::Puzzle>(),
+    Entry::new::<day_4::Puzzle>(),
+    Entry::new::<day_5::Puzzle>(),
+    Entry::new::<day_6::Puzzle>(),
+    Entry::new::<day_7::Puzzle>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Entry> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+/// Frames of a day part drawn from its input.
+pub type Visualize = fn(&str, Part) -> Result<Vec<Frame>, Error>;
+
+/// Days that can be drawn, see `aoc viz`.
+pub const VISUALIZATIONS: &[(u8, Visualize)] = &[(4, visualize_day_4), (5, visualize_day_5)];
+
+fn visualize_day_4(input: &str, _: Part) -> Result<Vec<Frame>, Error> {
+    day_4::visualize(input)
+}
+
+fn visualize_day_5(input: &str, part: Part) -> Result<Vec<Frame>, Error> {
+    let crate_mover_model = match part {
+        Part::A => day_5::CrateMoverModel::M9000,
+        Part::B => day_5::CrateMoverModel::M9001,
+    };
+
+    day_5::visualize(input, crate_mover_model)
+}
+
+pub fn find_visualization(day: u8) -> Option<Visualize> {
+    VISUALIZATIONS
+        .iter()
+        .find(|(visualized, _)| *visualized == day)
+        .map(|(_, visualize)| *visualize)
+}
+
+/// Root of the cargo workspace.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner crate lives inside the workspace.")
+}
+
+/// Registry of answers known to be correct, see [`crate::answers::Answers`].
+pub fn answers_path() -> PathBuf {
+    workspace_root().join("answers.txt")
+}
+
+/// Puzzle input committed for a day: `day_N/src/input.txt`.
+pub fn default_input_path(day: u8) -> PathBuf {
+    workspace_root()
+        .join(format!("day_{}", day))
+        .join("src")
+        .join("input.txt")
+}
+
+/// Config file used when none is passed: `AOC_CONFIG`, else `aoc.toml` of the workspace when it exists.
+pub fn default_config_path() -> Option<PathBuf> {
+    match std::env::var_os("AOC_CONFIG") {
+        Some(path) => Some(path.into()),
+        None => Some(workspace_root().join("aoc.toml")).filter(|path| path.exists()),
+    }
+}
+
+/// Config at `path`, else at [`default_config_path`], else an empty one.
+pub fn load_config(path: Option<&Path>) -> Result<Config, Error> {
+    match path.map(Path::to_path_buf).or_else(default_config_path) {
+        Some(path) => Config::load(&path),
+        None => Ok(Config::default()),
+    }
+}
+
+/// Input of a day set in `config`, else [`default_input`].
+pub fn configured_input(config: &Config, day: u8) -> Input {
+    config
+        .input(day)
+        .map(Input::File)
+        .unwrap_or_else(|| default_input(day))
+}
+
+/// Input of a day: the committed one, else the one in input cache (see `aoc fetch`).
+fn default_input(day: u8) -> Input {
+    let committed = default_input_path(day);
+
+    if !committed.exists() {
+        let cached = InputCache::from_env()
+            .map(|cache| cache.path(aoc_input::DEFAULT_YEAR, day))
+            .filter(|path| path.exists());
+
+        if let Some(path) = cached {
+            return Input::File(path);
+        }
+    }
+
+    Input::File(committed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_input_path, find, find_visualization, DAYS};
+
+    #[test]
+    fn should_register_days_in_order() {
+        for (idx, entry) in DAYS.iter().enumerate() {
+            assert_eq!(idx + 1, entry.day as usize);
+        }
+    }
+
+    #[test]
+    fn should_find_day() {
+        assert_eq!("Supply Stacks", find(5).unwrap().title);
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn should_find_visualization() {
+        let visualize = find_visualization(5).unwrap();
+        let frames = visualize("[A]\n 1   2 \n\nmove 1 from 1 to 2", aoc_common::Part::A).unwrap();
+
+        assert_eq!(2, frames.len());
+        assert!(find_visualization(6).is_none());
+    }
+
+    #[test]
+    fn should_point_to_committed_inputs() {
+        assert!(default_input_path(1).exists());
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const README: &str = include_str!("../templates/README.md.tmpl");
+const LIB: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN: &str = include_str!("../templates/main.rs.tmpl");
+const FUZZ_TARGET: &str = include_str!("../templates/fuzz_target.rs.tmpl");
+
+const DAY_NAMES: [&str; 25] = [
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+    "Twenty",
+    "Twenty-One",
+    "Twenty-Two",
+    "Twenty-Three",
+    "Twenty-Four",
+    "Twenty-Five",
+];
+
+/// A file listing every day, where the new one has to be added after the last one.
+struct Registration {
+    path: &'static str,
+    /// Start of lines (indentation excluded) registering a day.
+    prefix: &'static str,
+    line: fn(u8) -> String,
+}
+
+const REGISTRATIONS: [Registration; 5] = [
+    Registration {
+        path: "Cargo.toml",
+        prefix: "\"day_",
+        line: |day| format!("    \"day_{}\",", day),
+    },
+    Registration {
+        path: "aoc/Cargo.toml",
+        prefix: "day_",
+        line: |day| format!("day_{} = {{ path = \"../day_{}\" }}", day, day),
+    },
+    Registration {
+        path: "fuzz/Cargo.toml",
+        prefix: "day_",
+        line: |day| format!("day_{} = {{ path = \"../day_{}\" }}", day, day),
+    },
+    Registration {
+        path: "aoc/src/registry.rs",
+        prefix: "Entry::new::<day_",
+        line: |day| format!("    Entry::new::<day_{}::Puzzle>(),", day),
+    },
+    Registration {
+        path: "aoc/benches/days/main.rs",
+        prefix: "bench::<day_",
+        line: |day| {
+            format!(
+                "    bench::<day_{}::Puzzle>(&config, &mut rows, &mut timings);",
+                day
+            )
+        },
+    },
+];
+
+fn render(template: &str, day: u8, title: &str) -> String {
+    template
+        .replace("{day}", &day.to_string())
+        .replace("{day_name}", DAY_NAMES[day as usize - 1])
+        .replace("{title}", title)
+}
+
+/// Insert `line` after the last line starting with `prefix`.
+fn register(content: &str, prefix: &str, line: &str) -> Option<String> {
+    let lines = content.lines().collect::<Vec<&str>>();
+    let last = lines
+        .iter()
+        .rposition(|l| l.trim_start().starts_with(prefix))?;
+    let mut registered = lines[..=last].join("\n");
+
+    registered.push('\n');
+    registered.push_str(line);
+
+    for l in &lines[last + 1..] {
+        registered.push('\n');
+        registered.push_str(l);
+    }
+    if content.ends_with('\n') {
+        registered.push('\n');
+    }
+
+    Some(registered)
+}
+
+/// Create `day_N` crate in workspace `root` and register it in workspace, runner, benchmarks and fuzz targets.
+/// Returns created and updated files. Nothing is written when day can't be registered.
+pub fn scaffold(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day has to be in: 1..25, got: {}.", day));
+    }
+
+    let dir = root.join(format!("day_{}", day));
+
+    if dir.exists() {
+        return Err(format!("Day already exists: {}.", dir.display()));
+    }
+
+    let mut registered = Vec::new();
+
+    for registration in &REGISTRATIONS {
+        let path = root.join(registration.path);
+        let content = fs::read_to_string(&path)
+            .map_err(|err| format!("Unable to read: {}. {}", path.display(), err))?;
+        let line = (registration.line)(day);
+
+        if content.lines().any(|l| l == line) {
+            return Err(format!(
+                "Day {} is already registered in: {}.",
+                day,
+                path.display()
+            ));
+        }
+
+        let content = register(&content, registration.prefix, &line).ok_or_else(|| {
+            format!(
+                "Unable to find where to register day in: {}.",
+                path.display()
+            )
+        })?;
+        registered.push((path, content));
+    }
+
+    // Fuzz targets are declared at the end of fuzz manifest.
+    if let Some((_, manifest)) = registered
+        .iter_mut()
+        .find(|(path, _)| path.ends_with("fuzz/Cargo.toml"))
+    {
+        manifest.push_str(&format!(
+            "\n[[bin]]\nname = \"day_{day}\"\npath = \"fuzz_targets/day_{day}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+            day = day
+        ));
+    }
+
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TOML, day, title)),
+        (dir.join("README.md"), render(README, day, title)),
+        (dir.join("src/lib.rs"), render(LIB, day, title)),
+        (dir.join("src/main.rs"), render(MAIN, day, title)),
+        (dir.join("src/test_inputs/test_input_1.txt"), String::new()),
+        (
+            root.join(format!("fuzz/fuzz_targets/day_{}.rs", day)),
+            render(FUZZ_TARGET, day, title),
+        ),
+    ];
+
+    for dir in [dir.join("src/test_inputs"), root.join("fuzz/fuzz_targets")] {
+        fs::create_dir_all(&dir)
+            .map_err(|err| format!("Unable to create: {}. {}", dir.display(), err))?;
+    }
+
+    files
+        .into_iter()
+        .chain(registered)
+        .map(|(path, content)| {
+            fs::write(&path, content)
+                .map(|_| path.clone())
+                .map_err(|err| format!("Unable to write: {}. {}", path.display(), err))
+        })
+        .collect()
+}
+
+/// Add the new day to the README days section with `aoc readme`, run by cargo since this binary doesn't have the day yet.
+/// Returns false when cargo fails, eg: new day doesn't compile.
+pub fn regenerate_readme(root: &Path) -> io::Result<bool> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+
+    Command::new(cargo)
+        .current_dir(root)
+        .args(["run", "--quiet", "--release", "-p", "aoc", "--", "readme"])
+        .status()
+        .map(|status| status.success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{register, render, scaffold, LIB, README, REGISTRATIONS};
+    use rstest::rstest;
+    use std::{fs, path::PathBuf};
+
+    #[rstest]
+    #[case("a\nday_1\nday_2\nb\n", "day_", "day_3", "a\nday_1\nday_2\nday_3\nb\n")]
+    #[case(
+        "    \"day_1\",\n]",
+        "\"day_",
+        "    \"day_2\",",
+        "    \"day_1\",\n    \"day_2\",\n]"
+    )]
+    #[case("day_1", "day_", "day_2", "day_1\nday_2")]
+    fn should_register_after_last_day(
+        #[case] content: &str,
+        #[case] prefix: &str,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(expected, register(content, prefix, line).unwrap());
+    }
+
+    #[test]
+    fn should_not_register_without_days() {
+        assert_eq!(None, register("[workspace]\n", "\"day_", "    \"day_1\","));
+    }
+
+    #[test]
+    fn should_render_templates() {
+        let lib = render(LIB, 8, "Treetop Tree House");
+
+        assert!(lib.contains("const DAY: u8 = 8;"));
+        assert!(lib.contains("const TITLE: &'static str = \"Treetop Tree House\";"));
+        assert!(lib.contains("test_input_1.txt"));
+        assert!(!lib.contains("{day}"));
+
+        let readme = render(README, 8, "Treetop Tree House");
+
+        assert!(readme.starts_with("# Day Eight Puzzle"));
+        assert!(readme.contains("https://adventofcode.com/2022/day/8"));
+    }
+
+    /// Workspace with only the files a day is registered in.
+    fn fake_workspace(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        for registration in &REGISTRATIONS {
+            let path = root.join(registration.path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, format!("before\n{}\nafter\n", (registration.line)(1))).unwrap();
+        }
+
+        root
+    }
+
+    #[test]
+    fn should_scaffold_day() {
+        let root = fake_workspace("new");
+        let files = scaffold(&root, 2, "Rock Paper Scissors").unwrap();
+
+        assert_eq!(11, files.len());
+        assert!(root.join("fuzz/fuzz_targets/day_2.rs").exists());
+        assert!(root.join("day_2/src/test_inputs/test_input_1.txt").exists());
+        assert!(fs::read_to_string(root.join("day_2/src/main.rs"))
+            .unwrap()
+            .contains("use day_2::Puzzle;"));
+
+        for registration in &REGISTRATIONS {
+            let content = fs::read_to_string(root.join(registration.path)).unwrap();
+
+            assert!(content.starts_with(&format!(
+                "before\n{}\n{}\nafter\n",
+                (registration.line)(1),
+                (registration.line)(2)
+            )));
+        }
+        assert!(fs::read_to_string(root.join("fuzz/Cargo.toml"))
+            .unwrap()
+            .ends_with(
+                "path = \"fuzz_targets/day_2.rs\"\ntest = false\ndoc = false\nbench = false\n"
+            ));
+
+        assert!(scaffold(&root, 2, "Rock Paper Scissors").is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(26)]
+    fn should_not_scaffold_day(#[case] day: u8) {
+        let root = fake_workspace(&format!("invalid_{}", day));
+
+        assert!(scaffold(&root, day, "Title").is_err());
+        assert!(!root.join(format!("day_{}", day)).exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
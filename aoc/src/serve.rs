@@ -0,0 +1,381 @@
+//! `aoc serve`: solve puzzle inputs posted over http, with the same solvers as `aoc run`.
+
+use aoc_common::{json_string, Error, Input, Part, Report};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{registry, verify::panic_message};
+
+/// Larger bodies are rejected, a few times the largest committed input (about 10 KB).
+const MAX_BODY: usize = 64 * 1024;
+/// Larger request lines and headers are rejected, together.
+const MAX_HEAD: usize = 8 * 1024;
+/// Each connection has a thread, connections over this many are answered straight away with a `503`.
+const MAX_CONNECTIONS: usize = 64;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// A json response.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    /// Status line, eg: `200 OK`.
+    pub status: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn error(status: &'static str, message: &str) -> Self {
+        Self {
+            status,
+            body: format!("{{\"error\":{}}}", json_string(message)),
+        }
+    }
+}
+
+/// Answer connections until the listener fails, each connection on its own thread.
+/// Clients have `timeout` to send their whole request, else they get a `408`.
+pub fn serve(listener: TcpListener, timeout: Duration) -> io::Result<()> {
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+
+        if connections.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+            connections.fetch_sub(1, Ordering::SeqCst);
+            let response = Response::error(
+                "503 Service Unavailable",
+                "Too many connections, try again later.",
+            );
+            tracing::info!(status = response.status, "response");
+            let _ = write_response(stream, &response);
+            continue;
+        }
+
+        let connections = Arc::clone(&connections);
+        thread::spawn(move || {
+            // A broken connection only fails its own request.
+            let _ = handle(stream, timeout);
+            connections.fetch_sub(1, Ordering::SeqCst);
+        });
+    }
+
+    Ok(())
+}
+
+/// Reads a stream until a deadline, so that a client sending a byte now and then can't keep its thread forever.
+struct Deadline {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for Deadline {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+
+        if left.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        self.stream.read(buf)
+    }
+}
+
+/// Read a request and write its response.
+fn handle(stream: TcpStream, timeout: Duration) -> io::Result<()> {
+    let mut reader = BufReader::new(Deadline {
+        stream: stream.try_clone()?,
+        deadline: Instant::now() + timeout,
+    });
+    let response = match read_request(&mut reader) {
+        Ok(Ok(request)) => respond(&request.method, &request.path, request.body),
+        Ok(Err(response)) => response,
+        // Read timeouts are `WouldBlock` on unix.
+        Err(err)
+            if matches!(
+                err.kind(),
+                io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+            ) =>
+        {
+            Response::error("408 Request Timeout", "Request wasn't sent in time.")
+        }
+        Err(err) => return Err(err),
+    };
+    tracing::info!(status = response.status, "response");
+
+    write_response(stream, &response)
+}
+
+fn write_response(mut stream: TcpStream, response: &Response) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()?;
+    stream.shutdown(Shutdown::Both)
+}
+
+/// A request, or the response to a malformed one.
+fn read_request(reader: &mut impl BufRead) -> io::Result<Result<Request, Response>> {
+    // Lines are read up to the limit, not up to a newline that may never come.
+    let mut head = reader.by_ref().take(MAX_HEAD as u64);
+    let mut request_line = String::new();
+    head.read_line(&mut request_line)?;
+
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if head.read_line(&mut header)? == 0 {
+            if head.limit() == 0 {
+                return Ok(Err(Response::error(
+                    "431 Request Header Fields Too Large",
+                    &format!(
+                        "Request line and headers are larger than {} bytes.",
+                        MAX_HEAD
+                    ),
+                )));
+            }
+            break;
+        }
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = Some(value.trim().parse::<usize>().map_err(|_| {
+                    Response::error("400 Bad Request", "Invalid Content-Length header.")
+                }));
+            }
+        }
+    }
+
+    let mut request_line = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Ok(Err(Response::error(
+            "400 Bad Request",
+            "Invalid request line.",
+        )));
+    };
+    tracing::info!(method, path, "request");
+
+    let content_length = match content_length {
+        Some(Ok(length)) => length,
+        Some(Err(response)) => return Ok(Err(response)),
+        None if method == "POST" => {
+            return Ok(Err(Response::error(
+                "411 Length Required",
+                "Content-Length header is required.",
+            )))
+        }
+        None => 0,
+    };
+
+    if content_length > MAX_BODY {
+        return Ok(Err(Response::error(
+            "413 Payload Too Large",
+            &format!("Input is larger than {} bytes.", MAX_BODY),
+        )));
+    }
+
+    // Body grows with what is actually sent, not with what the header claims.
+    let mut body = Vec::new();
+    reader.take(content_length as u64).read_to_end(&mut body)?;
+
+    if body.len() < content_length {
+        return Ok(Err(Response::error(
+            "400 Bad Request",
+            "Body is shorter than Content-Length header.",
+        )));
+    }
+
+    Ok(Ok(Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body,
+    }))
+}
+
+/// Response to `POST /day/{n}/part/{a|b}` with puzzle input as body. Eg:
+/// `{"day":1,"part":"A","answer":"68467","elapsed_ms":0.154,"input_path":null}`.
+/// Failed solvers get a `422` with the error and its rendered diagnostic, panicking ones a `500`.
+pub fn respond(method: &str, path: &str, body: Vec<u8>) -> Response {
+    let route = match path.split('/').collect::<Vec<&str>>().as_slice() {
+        ["", "day", day, "part", part] => Some((*day, *part)),
+        _ => None,
+    };
+    let Some((day, part)) = route else {
+        return Response::error("404 Not Found", &format!("Unknown path: {}.", path));
+    };
+
+    if method != "POST" {
+        return Response::error("405 Method Not Allowed", "Post puzzle input to solve it.");
+    }
+
+    let Some(entry) = day.parse().ok().and_then(registry::find) else {
+        return Response::error("404 Not Found", &format!("Day {} is not solved yet.", day));
+    };
+    let part = match part.parse::<Part>() {
+        Ok(part) => part,
+        Err(err) => return Response::error("404 Not Found", &err),
+    };
+    let content = match String::from_utf8(body) {
+        Ok(content) => content,
+        Err(_) => return Response::error("400 Bad Request", "Input is not valid UTF-8."),
+    };
+
+    let start = Instant::now();
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        (entry.solve)(&mut content.as_bytes(), part, &entry.default_params())
+    }));
+    let elapsed = start.elapsed();
+
+    match solved {
+        Ok(Ok(answer)) => Response {
+            status: "200 OK",
+            body: Report {
+                day: entry.day,
+                part: part.to_string(),
+                answer,
+                elapsed,
+                input: &Input::Str(content),
+            }
+            .to_json(),
+        },
+        Ok(Err(err)) => {
+            let err = err.with_file("<body>");
+
+            Response {
+                status: match err {
+                    Error::Io(_) => "400 Bad Request",
+                    Error::Parse(_) | Error::NoAnswer(_) => "422 Unprocessable Entity",
+                },
+                body: format!(
+                    "{{\"day\":{},\"part\":{},\"error\":{},\"diagnostic\":{}}}",
+                    entry.day,
+                    json_string(&part.to_string()),
+                    json_string(&err.to_string()),
+                    json_string(&err.render())
+                ),
+            }
+        }
+        Err(payload) => Response::error(
+            "500 Internal Server Error",
+            &format!("Solver panicked: {}", panic_message(payload)),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_request, respond, Response};
+    use rstest::rstest;
+
+    fn post(path: &str, body: &str) -> Response {
+        respond("POST", path, body.as_bytes().to_vec())
+    }
+
+    #[rstest]
+    #[case("/day/1/part/a", "1000\n2000\n\n3000", "\"answer\":\"3000\"")]
+    #[case("/day/1/part/B", "1000\n2000\n\n3000", "\"answer\":\"6000\"")]
+    #[case("/day/6/part/a", "mjqjpqmgbljsphdztnvjfqwrcgsmlb", "\"answer\":\"7\"")]
+    fn should_solve_posted_input(#[case] path: &str, #[case] body: &str, #[case] expected: &str) {
+        let response = post(path, body);
+
+        assert_eq!("200 OK", response.status);
+        assert!(response.body.contains(expected), "{}", response.body);
+        assert!(response.body.contains("\"elapsed_ms\":"));
+    }
+
+    #[rstest]
+    #[case("/day/1", "404 Not Found")]
+    #[case("/day/42/part/a", "404 Not Found")]
+    #[case("/day/x/part/a", "404 Not Found")]
+    #[case("/day/1/part/c", "404 Not Found")]
+    #[case("/day/2/part/a", "422 Unprocessable Entity")]
+    fn should_reject_request(#[case] path: &str, #[case] status: &str) {
+        assert_eq!(status, post(path, "1000").status);
+    }
+
+    #[test]
+    fn should_point_to_offending_text() {
+        let response = post("/day/4/part/a", "2-4,6-8\n2-x,3-7");
+
+        assert_eq!(
+            "{\"day\":4,\"part\":\"A\",\"error\":\"<body>:2:3: Expected a number.\",\"diagnostic\":\"error: Expected a number.\\n --> <body>:2:3\\n  |\\n2 | 2-x,3-7\\n  |   ^\"}",
+            response.body
+        );
+    }
+
+    #[test]
+    fn should_only_solve_posted_input() {
+        assert_eq!(
+            "405 Method Not Allowed",
+            respond("GET", "/day/1/part/a", Vec::new()).status
+        );
+    }
+
+    #[rstest]
+    #[case("POST /day/1/part/a HTTP/1.1\r\nContent-Length: 4\r\n\r\n1000", Ok(("POST", "/day/1/part/a", "1000")))]
+    #[case("GET / HTTP/1.1\r\nHost: localhost\r\n\r\n", Ok(("GET", "/", "")))]
+    #[case("POST /day/1/part/a HTTP/1.1\r\n\r\n1000", Err("411 Length Required"))]
+    #[case(
+        "POST /day/1/part/a HTTP/1.1\r\ncontent-length: many\r\n\r\n",
+        Err("400 Bad Request")
+    )]
+    #[case(
+        "POST /day/1/part/a HTTP/1.1\r\nContent-Length: 999999999\r\n\r\n",
+        Err("413 Payload Too Large")
+    )]
+    #[case(
+        "POST /day/1/part/a HTTP/1.1\r\nContent-Length: 65537\r\n\r\n",
+        Err("413 Payload Too Large")
+    )]
+    #[case(
+        "POST /day/1/part/a HTTP/1.1\r\nContent-Length: 5000\r\n\r\n1000",
+        Err("400 Bad Request")
+    )]
+    #[case("\r\n", Err("400 Bad Request"))]
+    #[case(
+        &format!("GET / HTTP/1.1\r\nX-Padding: {}\r\n\r\n", "a".repeat(8 * 1024)),
+        Err("431 Request Header Fields Too Large")
+    )]
+    #[case(
+        &format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(8 * 1024)),
+        Err("431 Request Header Fields Too Large")
+    )]
+    fn should_read_request(
+        #[case] request: &str,
+        #[case] expected: Result<(&str, &str, &str), &str>,
+    ) {
+        let read = read_request(&mut request.as_bytes()).unwrap();
+
+        match (read, expected) {
+            (Ok(request), Ok(expected)) => assert_eq!(
+                expected,
+                (
+                    request.method.as_str(),
+                    request.path.as_str(),
+                    std::str::from_utf8(&request.body).unwrap()
+                )
+            ),
+            (Err(response), Err(status)) => assert_eq!(status, response.status),
+            (read, expected) => panic!("Expected: {:?}, got: {:?}", expected, read),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_common::format_duration;
+
+use crate::verify::{Outcome, Status};
+
+/// Short status of an outcome, errors are too long for a table cell.
+pub fn status(outcome: &Outcome) -> String {
+    match &outcome.status {
+        Status::Pass => String::from("pass"),
+        Status::Fail { expected } => format!("FAIL, expected: {}", expected),
+        Status::Missing => String::from("missing"),
+        Status::NoInput => String::from("no input"),
+        Status::Unverified => String::from("unverified"),
+        Status::Error(_) => String::from("ERROR"),
+        Status::Panic(message) => format!("PANIC: {}", message),
+    }
+}
+
+/// Markdown table with a row per day part: day, part, answer, time and verification status.
+pub fn table(outcomes: &[Outcome]) -> String {
+    let rows = outcomes
+        .iter()
+        .map(|outcome| {
+            vec![
+                outcome.day.to_string(),
+                outcome.part.to_string(),
+                outcome.answer.clone().unwrap_or_default(),
+                match outcome.answer {
+                    Some(_) => format_duration(outcome.elapsed),
+                    None => String::new(),
+                },
+                status(outcome),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    markdown(&["day", "part", "answer", "time", "status"], &rows, &[0, 3])
+}
+
+/// Markdown table with columns padded to their widest cell, numbers of `right_aligned` columns are right aligned.
+pub fn markdown(header: &[&str], rows: &[Vec<String>], right_aligned: &[usize]) -> String {
+    let header = header
+        .iter()
+        .map(|cell| cell.to_string())
+        .collect::<Vec<_>>();
+    let mut widths = header
+        .iter()
+        .map(|cell| cell.chars().count())
+        .collect::<Vec<_>>();
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |row: &[String]| {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(idx, (cell, width))| match right_aligned.contains(&idx) {
+                true => format!("{:>width$}", cell, width = width),
+                false => format!("{:<width$}", cell, width = width),
+            })
+            .collect::<Vec<_>>();
+
+        format!("| {} |\n", cells.join(" | "))
+    };
+    let separator = widths
+        .iter()
+        .enumerate()
+        .map(|(idx, width)| match right_aligned.contains(&idx) {
+            true => format!("{}:", "-".repeat(width + 1)),
+            false => format!(":{}", "-".repeat(width + 1)),
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = line(&header);
+    table.push_str(&format!("|{}|\n", separator.join("|")));
+
+    for row in rows {
+        table.push_str(&line(row));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::table;
+    use crate::verify::{Outcome, Status};
+    use aoc_common::Part;
+    use std::time::Duration;
+
+    fn outcome(day: u8, answer: Option<&str>, status: Status) -> Outcome {
+        Outcome {
+            day,
+            part: Part::A,
+            input_hash: String::new(),
+            answer: answer.map(String::from),
+            elapsed: Duration::from_micros(1500),
+            status,
+        }
+    }
+
+    #[test]
+    fn should_render_a_row_per_outcome() {
+        let table = table(&[
+            outcome(1, Some("68467"), Status::Pass),
+            outcome(
+                5,
+                Some("ABC"),
+                Status::Fail {
+                    expected: String::from("MQSHJMWNH"),
+                },
+            ),
+            outcome(7, None, Status::NoInput),
+            outcome(12, None, Status::Panic(String::from("Broken solver."))),
+        ]);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(6, lines.len());
+        assert_eq!(
+            "| day | part | answer |   time | status                    |",
+            lines[0]
+        );
+        assert_eq!(
+            "|----:|:-----|:-------|-------:|:--------------------------|",
+            lines[1]
+        );
+        assert_eq!(
+            "|   1 | A    | 68467  | 1.5 ms | pass                      |",
+            lines[2]
+        );
+        assert_eq!(
+            "|   5 | A    | ABC    | 1.5 ms | FAIL, expected: MQSHJMWNH |",
+            lines[3]
+        );
+        assert_eq!(
+            "|   7 | A    |        |        | no input                  |",
+            lines[4]
+        );
+        assert_eq!(
+            "|  12 | A    |        |        | PANIC: Broken solver.     |",
+            lines[5]
+        );
+    }
+}
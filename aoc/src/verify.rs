@@ -0,0 +1,289 @@
+use aoc_common::{Entry, Error, Input, Params, Part};
+use std::{
+    any::Any,
+    fmt, io,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::answers::{hash_input, Answer, Answers};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// No answer recorded for this input.
+    Missing,
+    /// Input can't be found, eg: day input was never committed.
+    NoInput,
+    /// Solved with other params than default ones, which answers are recorded with.
+    Unverified,
+    Error(Error),
+    /// Solver panicked, with panic message.
+    Panic(String),
+}
+
+/// Result of verifying a day part against the answers registry.
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub input_hash: String,
+    pub answer: Option<String>,
+    /// Time spent solving, input reading excluded.
+    pub elapsed: Duration,
+    pub status: Status,
+}
+
+/// Message of a caught panic, see [`std::panic::catch_unwind`].
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("Unknown panic."))
+}
+
+/// Solve a day part with `params` and compare answer with the recorded one. Answers are recorded with default params,
+/// so other params give [`Status::Unverified`]. A panicking solver is reported as [`Status::Panic`], so that other days can still be verified.
+pub fn verify(
+    entry: &Entry,
+    input: &Input,
+    part: Part,
+    params: &Params,
+    answers: &Answers,
+) -> Outcome {
+    let mut outcome = Outcome {
+        day: entry.day,
+        part,
+        input_hash: String::new(),
+        answer: None,
+        elapsed: Duration::ZERO,
+        status: Status::NoInput,
+    };
+
+    let content = match input.read_to_string() {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return outcome,
+        Err(err) => {
+            outcome.status = Status::Error(err.into());
+            return outcome;
+        }
+    };
+    outcome.input_hash = hash_input(&content);
+
+    let start = Instant::now();
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        (entry.solve)(&mut content.as_bytes(), part, params)
+    }));
+    outcome.elapsed = start.elapsed();
+
+    let answer = match solved {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(err)) => {
+            outcome.status = Status::Error(err.with_file(input.to_string()));
+            return outcome;
+        }
+        Err(payload) => {
+            outcome.status = Status::Panic(panic_message(payload));
+            return outcome;
+        }
+    };
+
+    outcome.status = match answers.expected(entry.day, part, &outcome.input_hash) {
+        _ if *params != entry.default_params() => Status::Unverified,
+        Some(expected) if expected == answer => Status::Pass,
+        Some(expected) => Status::Fail {
+            expected: expected.to_string(),
+        },
+        None => Status::Missing,
+    };
+    outcome.answer = Some(answer);
+
+    outcome
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.status,
+            Status::Fail { .. } | Status::Error(_) | Status::Panic(_)
+        )
+    }
+
+    /// Answer to record when it's missing in registry.
+    pub fn to_record(&self) -> Option<Answer> {
+        match (&self.status, &self.answer) {
+            (Status::Missing, Some(answer)) => Some(Answer {
+                day: self.day,
+                part: self.part,
+                input_hash: self.input_hash.clone(),
+                answer: answer.clone(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} part {}: ", self.day, self.part)?;
+
+        let answer = self.answer.as_deref().unwrap_or_default();
+
+        match &self.status {
+            Status::Pass => write!(f, "pass ({})", answer),
+            Status::Fail { expected } => {
+                write!(f, "FAIL, expected: {} got: {}", expected, answer)
+            }
+            Status::Missing => write!(f, "missing, no recorded answer for: {}", answer),
+            Status::NoInput => write!(f, "skipped, no input"),
+            Status::Unverified => write!(f, "unverified, solved with other params ({})", answer),
+            Status::Error(err) => write!(f, "ERROR\n{}", err.render()),
+            Status::Panic(message) => write!(f, "PANIC: {}", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify, Status};
+    use crate::answers::{hash_input, Answer, Answers};
+    use aoc_common::{Entry, Input, Params, Part};
+
+    const INPUT: &str = "1000\n2000\n\n5000\n";
+
+    fn answers(answer: &str) -> Answers {
+        let mut answers = Answers::default();
+        answers.add(Answer {
+            day: 1,
+            part: Part::A,
+            input_hash: hash_input(INPUT),
+            answer: answer.to_string(),
+        });
+        answers
+    }
+
+    fn entry() -> Entry {
+        Entry::new::<day_1::Puzzle>()
+    }
+
+    fn params() -> Params {
+        entry().default_params()
+    }
+
+    #[test]
+    fn should_pass() {
+        let outcome = verify(
+            &entry(),
+            &Input::Str(INPUT.into()),
+            Part::A,
+            &params(),
+            &answers("5000"),
+        );
+
+        assert_eq!(Status::Pass, outcome.status);
+        assert!(!outcome.is_failure());
+        assert!(outcome.to_record().is_none());
+    }
+
+    #[test]
+    fn should_fail() {
+        let outcome = verify(
+            &entry(),
+            &Input::Str(INPUT.into()),
+            Part::A,
+            &params(),
+            &answers("42"),
+        );
+
+        assert_eq!(
+            Status::Fail {
+                expected: String::from("42")
+            },
+            outcome.status
+        );
+        assert!(outcome.is_failure());
+    }
+
+    #[test]
+    fn should_report_missing_answer() {
+        let outcome = verify(
+            &entry(),
+            &Input::Str(INPUT.into()),
+            Part::B,
+            &params(),
+            &answers("5000"),
+        );
+
+        assert_eq!(Status::Missing, outcome.status);
+        assert_eq!("8000", outcome.to_record().unwrap().answer);
+    }
+
+    #[test]
+    fn should_not_compare_answers_of_other_params() {
+        let mut params = params();
+        params.set(entry().params, "top", 2).unwrap();
+        let outcome = verify(
+            &entry(),
+            &Input::Str(INPUT.into()),
+            Part::A,
+            &params,
+            &answers("42"),
+        );
+
+        assert_eq!(Status::Unverified, outcome.status);
+        assert!(!outcome.is_failure());
+        assert!(outcome.to_record().is_none());
+    }
+
+    #[test]
+    fn should_skip_missing_input() {
+        let input = Input::File("./this/file/does/not/exist.txt".into());
+        let outcome = verify(&entry(), &input, Part::A, &params(), &answers("5000"));
+
+        assert_eq!(Status::NoInput, outcome.status);
+        assert!(!outcome.is_failure());
+    }
+
+    #[test]
+    fn should_report_errors() {
+        let outcome = verify(
+            &entry(),
+            &Input::Str("12\nx".into()),
+            Part::A,
+            &params(),
+            &answers("5000"),
+        );
+
+        assert!(matches!(outcome.status, Status::Error(_)));
+        assert!(outcome.is_failure());
+    }
+
+    #[test]
+    fn should_report_panics() {
+        let entry = Entry {
+            day: 1,
+            title: "Broken",
+            params: &[],
+            option: aoc_common::PART_OPTION,
+            solve: |_, _, _| panic!("Broken solver."),
+            profile: |_, _, _| panic!("Broken solver."),
+        };
+        let outcome = verify(
+            &entry,
+            &Input::Str(INPUT.into()),
+            Part::A,
+            &params(),
+            &answers("5000"),
+        );
+
+        assert_eq!(
+            Status::Panic(String::from("Broken solver.")),
+            outcome.status
+        );
+        assert!(outcome.is_failure());
+    }
+}
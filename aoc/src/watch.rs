@@ -0,0 +1,350 @@
+//! `aoc watch`: re-run a day when its sources or inputs change, and diff answers with the previous run.
+
+use aoc_common::Part;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+/// Modification time of every watched file.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, SystemTime>);
+
+impl Snapshot {
+    /// Every file of `dirs` (recursively) and `files`, missing ones are skipped.
+    pub fn take(dirs: &[PathBuf], files: &[PathBuf]) -> io::Result<Self> {
+        let mut snapshot = Self::default();
+
+        for dir in dirs {
+            snapshot.add_dir(dir)?;
+        }
+        for file in files {
+            if let Ok(metadata) = fs::metadata(file) {
+                snapshot.0.insert(file.clone(), metadata.modified()?);
+            }
+        }
+
+        Ok(snapshot)
+    }
+
+    fn add_dir(&mut self, dir: &Path) -> io::Result<()> {
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+
+            if metadata.is_dir() {
+                self.add_dir(&entry.path())?;
+            } else {
+                self.0.insert(entry.path(), metadata.modified()?);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Files added, removed or modified since `previous`.
+    pub fn changed(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changed = self
+            .0
+            .iter()
+            .filter(|(path, modified)| previous.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+
+        changed.extend(
+            previous
+                .0
+                .keys()
+                .filter(|path| !self.0.contains_key(*path))
+                .cloned(),
+        );
+        changed.sort();
+        changed
+    }
+}
+
+/// Answers and test results of a run, failed parts have no answer.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Run {
+    pub answers: Vec<(Part, Option<String>)>,
+    /// Missing when tests don't build.
+    pub tests: Option<Tests>,
+    /// Error output of failed steps, eg: rendered parse errors or build errors.
+    pub errors: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Tests {
+    pub passed: usize,
+    /// Names of failed tests.
+    pub failed: Vec<String>,
+}
+
+/// Solve both parts of `day` then run its tests, with cargo so that changed sources are built.
+pub fn run(workspace_root: &Path, day: u8) -> io::Result<Run> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+    let day_arg = day.to_string();
+
+    let solved = Command::new(&cargo)
+        .current_dir(workspace_root)
+        .args(["run", "--quiet", "-p", "aoc", "--", "run", "--day"])
+        .args([&day_arg, "--format", "json"])
+        .output()?;
+    let tested = Command::new(&cargo)
+        .current_dir(workspace_root)
+        .args(["test", "--quiet", "-p", &format!("day_{}", day)])
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&solved.stdout);
+    let answers = [Part::A, Part::B]
+        .into_iter()
+        .map(|part| {
+            let answer = stdout
+                .lines()
+                .find(|line| string_field(line, "part").as_deref() == Some(&part.to_string()))
+                .and_then(|line| string_field(line, "answer"));
+            (part, answer)
+        })
+        .collect();
+
+    let tests = parse_tests(&String::from_utf8_lossy(&tested.stdout));
+    // Only output of failed steps, build warnings would hide what changed.
+    let errors = [
+        (!solved.status.success()).then_some(&solved.stderr),
+        tests.is_none().then_some(&tested.stderr),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|stderr| String::from_utf8_lossy(stderr).trim().to_string())
+    .collect::<Vec<_>>()
+    .join("\n");
+
+    Ok(Run {
+        answers,
+        tests,
+        errors,
+    })
+}
+
+/// Value of string field `key` of a json object on a single line, see [`aoc_common::Report::to_json`].
+fn string_field(line: &str, key: &str) -> Option<String> {
+    let start = line.find(&format!("\"{}\":\"", key))? + key.len() + 4;
+    let mut value = String::new();
+    let mut chars = line[start..].chars();
+
+    loop {
+        match chars.next()? {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'u' => {
+                    let code = chars.by_ref().take(4).collect::<String>();
+                    value.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                escaped => value.push(escaped),
+            },
+            c => value.push(c),
+        }
+    }
+}
+
+/// Sum of `cargo test --quiet` results, missing when no test ran (eg: build failed).
+fn parse_tests(output: &str) -> Option<Tests> {
+    let mut tests = None::<Tests>;
+
+    for line in output.lines() {
+        if let Some(result) = line.strip_prefix("test result: ") {
+            // Eg: `ok. 3 passed; 0 failed; 0 ignored`.
+            let passed = result
+                .split(';')
+                .find_map(|count| count.trim().strip_suffix(" passed"))
+                .and_then(|count| count.rsplit(' ').next()?.parse::<usize>().ok())
+                .unwrap_or(0);
+            tests.get_or_insert_with(Tests::default).passed += passed;
+        } else if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|line| line.strip_suffix(" stdout ----"))
+        {
+            tests
+                .get_or_insert_with(Tests::default)
+                .failed
+                .push(name.to_string());
+        }
+    }
+
+    tests
+}
+
+/// Answers and tests of `run`, compared with `previous` run. Eg:
+/// ```text
+/// part A: MQSHJMWNH (unchanged)
+/// part B: LLWJRBHVZ -> LLWJRBHVX
+/// tests: 22 passed, 1 failed: tests::should_move_crates
+/// ```
+pub fn report(previous: Option<&Run>, run: &Run) -> String {
+    let mut report = String::new();
+    let show = |answer: &Option<String>| answer.clone().unwrap_or_else(|| String::from("failed"));
+
+    for (part, answer) in &run.answers {
+        let before = previous.and_then(|previous| {
+            previous
+                .answers
+                .iter()
+                .find(|(previous_part, _)| previous_part == part)
+                .map(|(_, answer)| answer)
+        });
+
+        match before {
+            Some(before) if before == answer => {
+                writeln!(report, "part {}: {} (unchanged)", part, show(answer))
+            }
+            Some(before) => writeln!(
+                report,
+                "part {}: {} -> {}",
+                part,
+                show(before),
+                show(answer)
+            ),
+            None => writeln!(report, "part {}: {}", part, show(answer)),
+        }
+        .unwrap();
+    }
+
+    match &run.tests {
+        Some(tests) if tests.failed.is_empty() => {
+            write!(report, "tests: {} passed", tests.passed)
+        }
+        Some(tests) => write!(
+            report,
+            "tests: {} passed, {} failed: {}",
+            tests.passed,
+            tests.failed.len(),
+            tests.failed.join(", ")
+        ),
+        None => write!(report, "tests: build failed"),
+    }
+    .unwrap();
+
+    if !run.errors.is_empty() {
+        write!(report, "\n\n{}", run.errors).unwrap();
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_tests, report, string_field, Run, Snapshot, Tests};
+    use aoc_common::Part;
+    use rstest::rstest;
+    use std::{fs, time::SystemTime};
+
+    #[test]
+    fn should_find_changed_files() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        fs::create_dir_all(dir.join("test_inputs")).unwrap();
+        fs::write(dir.join("lib.rs"), "").unwrap();
+        fs::write(dir.join("test_inputs").join("input_test_1.txt"), "").unwrap();
+        let input = dir.join("input.txt");
+        let (dirs, files) = ([dir.clone()], [input.clone()]);
+
+        let before = Snapshot::take(&dirs, &files).unwrap();
+        assert_eq!(2, before.0.len(), "Missing input is skipped.");
+
+        fs::write(&input, "1000").unwrap();
+        let mut after = Snapshot::take(&dirs, &files).unwrap();
+        after.0.insert(dir.join("lib.rs"), SystemTime::UNIX_EPOCH);
+
+        assert_eq!(vec![input, dir.join("lib.rs")], after.changed(&before));
+        assert_eq!(
+            2,
+            Snapshot::default().changed(&before).len(),
+            "Removed files."
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[rstest]
+    #[case(
+        "{\"day\":1,\"part\":\"A\",\"answer\":\"68467\",\"elapsed_ms\":0.154}",
+        "answer",
+        Some("68467")
+    )]
+    #[case(
+        "{\"day\":1,\"part\":\"A\",\"answer\":\"a \\\"b\\\"\\n\\u0041\"}",
+        "answer",
+        Some("a \"b\"\nA")
+    )]
+    #[case("{\"day\":1,\"part\":\"A\"}", "part", Some("A"))]
+    #[case("{\"day\":1,\"part\":\"A\"}", "answer", None)]
+    #[case("{\"answer\":\"unterminated", "answer", None)]
+    fn should_read_string_field(
+        #[case] line: &str,
+        #[case] key: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(expected.map(str::to_string), string_field(line, key));
+    }
+
+    #[rstest]
+    #[case("\nrunning 3 tests\n...\ntest result: ok. 3 passed; 0 failed; 0 ignored\n\nrunning 0 tests\n\ntest result: ok. 0 passed; 0 failed\n", Some((3, vec![])))]
+    #[case("\nrunning 2 tests\nF.\nfailures:\n\n---- tests::should_move stdout ----\nassertion failed\n\nfailures:\n    tests::should_move\n\ntest result: FAILED. 1 passed; 1 failed; 0 ignored\n", Some((1, vec!["tests::should_move"])))]
+    #[case("", None)]
+    fn should_parse_tests(#[case] output: &str, #[case] expected: Option<(usize, Vec<&str>)>) {
+        assert_eq!(
+            expected.map(|(passed, failed)| Tests {
+                passed,
+                failed: failed.into_iter().map(str::to_string).collect()
+            }),
+            parse_tests(output)
+        );
+    }
+
+    fn run(a: Option<&str>, b: Option<&str>, tests: Option<Tests>) -> Run {
+        Run {
+            answers: vec![
+                (Part::A, a.map(str::to_string)),
+                (Part::B, b.map(str::to_string)),
+            ],
+            tests,
+            errors: String::new(),
+        }
+    }
+
+    #[test]
+    fn should_report_first_run() {
+        assert_eq!(
+            "part A: 24000\npart B: failed\ntests: build failed",
+            report(None, &run(Some("24000"), None, None))
+        );
+    }
+
+    #[test]
+    fn should_diff_answers_with_previous_run() {
+        let previous = run(Some("24000"), None, None);
+        let current = run(
+            Some("24000"),
+            Some("45000"),
+            Some(Tests {
+                passed: 4,
+                failed: vec![String::from("tests::should_sum")],
+            }),
+        );
+
+        assert_eq!(
+            "part A: 24000 (unchanged)\npart B: failed -> 45000\ntests: 4 passed, 1 failed: tests::should_sum",
+            report(Some(&previous), &current)
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! `aoc fetch` against a [`MockServer`] standing in for the puzzle site.
+
+use aoc_input::{InputCache, MockServer};
+use std::{fs, process::Command};
+
+#[test]
+fn should_fetch_inputs_into_cache_and_run_them() {
+    let server = MockServer::start("secret").with_input(2021, 1, "199\n200\n");
+    let cache =
+        InputCache::new(std::env::temp_dir().join(format!("aoc_fetch_{}", std::process::id())));
+    let aoc = |args: &[&str], session: &str| {
+        Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(args)
+            .env("AOC_CACHE_DIR", cache.dir())
+            .env("AOC_URL", server.url())
+            .env("AOC_SESSION", session)
+            .output()
+            .unwrap()
+    };
+
+    let output = aoc(&["fetch", "--day", "1", "--year", "2021"], "expired");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Session token is rejected"));
+
+    let output = aoc(&["fetch", "--day", "1", "--year", "2021"], "secret");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("fetched in"));
+    assert_eq!(
+        Some(String::from("199\n200\n")),
+        cache.get(2021, 1).unwrap()
+    );
+
+    let output = aoc(&["fetch", "--day", "1", "--year", "2021"], "secret");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already cached in"));
+    assert_eq!(2, server.requests().len());
+
+    let output = aoc(&["fetch", "--day", "2", "--year", "2021"], "secret");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not available yet"));
+
+    fs::remove_dir_all(cache.dir()).unwrap();
+}
@@ -0,0 +1,17 @@
+//! `aoc run` of committed inputs, verified against `answers.txt`.
+
+use std::process::Command;
+
+#[test]
+fn should_not_verify_answers_of_overridden_params() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--all", "--jobs", "2", "--param", "top=2"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("| pass "), "{}", stdout);
+    assert!(stdout.contains("| unverified "), "{}", stdout);
+    assert!(!stdout.contains("FAIL"), "{}", stdout);
+}
@@ -0,0 +1,169 @@
+//! `aoc serve` answering http requests on localhost.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Child, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Server process, killed when dropped.
+struct Server {
+    child: Child,
+    address: String,
+}
+
+impl Server {
+    /// Start serving on a free port.
+    fn start() -> Self {
+        Self::start_with(&[])
+    }
+
+    /// Start serving on a free port with more `serve` options.
+    fn start_with(options: &[&str]) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["serve", "--port", "0"])
+            .args(options)
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let address = line
+            .trim()
+            .strip_prefix("Listening on: http://")
+            .unwrap_or_else(|| panic!("Unexpected output: {}", line))
+            .to_string();
+
+        Self { child, address }
+    }
+
+    /// Status line and body of the response.
+    fn request(&self, method: &str, path: &str, body: &str) -> (String, String) {
+        let mut stream = TcpStream::connect(&self.address).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            method,
+            path,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        response(stream)
+    }
+}
+
+/// Status line and body of the response read from `stream`.
+fn response(mut stream: TcpStream) -> (String, String) {
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.lines().next().unwrap().trim_start_matches("HTTP/1.1 ");
+
+    (status.to_string(), body.to_string())
+}
+
+impl Server {
+    /// Connection that sent `head`, without its end.
+    fn connect(&self, head: &str) -> TcpStream {
+        let mut stream = TcpStream::connect(&self.address).unwrap();
+        stream.write_all(head.as_bytes()).unwrap();
+        stream
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn should_solve_posted_inputs() {
+    let server = Server::start();
+
+    let (status, body) = server.request(
+        "POST",
+        "/day/5/part/b",
+        "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2\n",
+    );
+    assert_eq!("200 OK", status);
+    assert!(
+        body.starts_with("{\"day\":5,\"part\":\"B\",\"answer\":\"MCD\",\"elapsed_ms\":"),
+        "{}",
+        body
+    );
+
+    let (status, body) = server.request("POST", "/day/2/part/a", "A Y\nD X\n");
+    assert_eq!("422 Unprocessable Entity", status);
+    assert!(body.contains("\"error\":\"<body>:2:1:"), "{}", body);
+
+    let (status, _) = server.request("POST", "/day/25/part/a", "");
+    assert_eq!("404 Not Found", status);
+
+    let (status, _) = server.request("GET", "/day/1/part/a", "");
+    assert_eq!("405 Method Not Allowed", status);
+}
+
+#[test]
+fn should_answer_concurrent_requests() {
+    let server = Server::start();
+
+    std::thread::scope(|scope| {
+        let answers = (0..8)
+            .map(|_| {
+                scope.spawn(|| {
+                    server.request("POST", "/day/6/part/b", "bvwbjplbgvbhsrlpgdmjqwftvncz")
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for answer in answers {
+            let (status, body) = answer.join().unwrap();
+            assert_eq!("200 OK", status);
+            assert!(body.contains("\"answer\":\"23\""), "{}", body);
+        }
+    });
+}
+
+#[test]
+fn should_reject_oversized_headers() {
+    let server = Server::start();
+    let stream = server.connect(&format!(
+        "POST /day/1/part/a HTTP/1.1\r\nX-Padding: {}",
+        "a".repeat(8 * 1024)
+    ));
+
+    let (status, body) = response(stream);
+    assert_eq!("431 Request Header Fields Too Large", status);
+    assert!(body.contains("larger than 8192 bytes"), "{}", body);
+}
+
+#[test]
+fn should_time_out_slow_clients() {
+    let server = Server::start_with(&["--timeout", "500"]);
+    let mut stream = server.connect("POST /day/1/part/a HTTP/1.1\r\nX-Slow: ");
+    stream
+        .set_read_timeout(Some(Duration::from_millis(100)))
+        .unwrap();
+    let start = Instant::now();
+
+    // A byte well within every read timeout, until the server gives up.
+    let mut first = [0; 1];
+    while stream.peek(&mut first).is_err() {
+        assert!(start.elapsed() < Duration::from_secs(5), "Still connected.");
+        stream.write_all(b"a").unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+    stream.set_read_timeout(None).unwrap();
+
+    let (status, _) = response(stream);
+    assert_eq!("408 Request Timeout", status);
+}
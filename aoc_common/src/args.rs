@@ -0,0 +1,367 @@
+use std::{
+    fmt,
+    io::BufRead,
+    process,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    config::{Config, ParamOverride},
+    init_tracing, Error, Format, Input, Params, Part, Report, Solution,
+};
+
+/// Puzzle option of day binaries solving a [`Part`].
+pub const PART_OPTION: &str = "puzzle option (A | B)";
+
+/// Usage line of a day binary, `option_name` describes its puzzle option.
+pub fn usage(option_name: &str) -> String {
+    format!(
+        "<input path | - | --input-str <input>> <{}> [--format <text | json>] [--param <name>=<N>]... [-v | -vv]",
+        option_name
+    )
+}
+
+/// Cli args shared by each day binary: `<input path> <puzzle option>`.
+/// Input path can be `-` to read stdin, or replaced by `--input-str <input>` to pass input inline.
+/// `--format <text | json>` selects how the answer is printed, `-v` / `-vv` traces solver steps,
+/// `--param <name>=<N>` changes a puzzle parameter (see [`crate::config`]).
+/// Puzzle option defaults to a [`Part`], but a day can ask for something else (eg: day 6 marker length).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args<O = Part> {
+    pub input: Input,
+    pub option: O,
+    pub format: Format,
+    /// Number of `-v`, see [`crate::init_tracing`].
+    pub verbosity: u8,
+    /// Puzzle parameters changed from the command line.
+    pub params: Vec<ParamOverride>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    MissingInput,
+    MissingOption(&'static str),
+    InvalidOption(String),
+    UnexpectedArg(String),
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::MissingInput => write!(f, "You have to pass input file."),
+            ArgsError::MissingOption(name) => write!(f, "You have to pass {}.", name),
+            ArgsError::InvalidOption(err) => write!(f, "{}", err),
+            ArgsError::UnexpectedArg(arg) => write!(f, "Unexpected argument: \"{}\".", arg),
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+impl<O> Args<O>
+where
+    O: FromStr,
+    O::Err: fmt::Display,
+{
+    /// Parse args (program name excluded).
+    /// `option_name` is used in error messages when puzzle option is missing.
+    pub fn parse<I>(args: I, option_name: &'static str) -> Result<Self, ArgsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        let mut input = None;
+        let mut option = None;
+        let mut format = Format::default();
+        let mut verbosity = 0;
+        let mut params = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-v" | "--verbose" => verbosity += 1,
+                "--param" => params.push(
+                    args.next()
+                        .ok_or(ArgsError::MissingOption("param"))?
+                        .parse()
+                        .map_err(ArgsError::InvalidOption)?,
+                ),
+                "-vv" => verbosity += 2,
+                "--format" => {
+                    format = args
+                        .next()
+                        .ok_or(ArgsError::MissingOption("format"))?
+                        .parse()
+                        .map_err(ArgsError::InvalidOption)?;
+                }
+                "--input-str" if input.is_none() => {
+                    input = Some(Input::Str(args.next().ok_or(ArgsError::MissingInput)?));
+                }
+                _ if input.is_none() => input = Some(Input::from_arg(&arg)),
+                _ if option.is_none() => {
+                    option = Some(
+                        arg.parse::<O>()
+                            .map_err(|err| ArgsError::InvalidOption(err.to_string()))?,
+                    );
+                }
+                _ => return Err(ArgsError::UnexpectedArg(arg)),
+            }
+        }
+
+        Ok(Self {
+            input: input.ok_or(ArgsError::MissingInput)?,
+            option: option.ok_or(ArgsError::MissingOption(option_name))?,
+            format,
+            verbosity,
+            params,
+        })
+    }
+
+    /// Parse args of current process, exits printing the error when they are not valid.
+    pub fn from_env(option_name: &'static str) -> Self {
+        Self::parse(std::env::args().skip(1), option_name).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            eprintln!("Usage: {}", usage(option_name));
+            process::exit(2);
+        })
+    }
+}
+
+impl<O> Args<O> {
+    /// Params of day `S`: defaults, changed by config file at `AOC_CONFIG` when it's set, then by `--param`.
+    /// Exits printing the error when they are not valid, eg: a param the day doesn't have.
+    pub fn params<S: Solution>(&self) -> Params {
+        let config = match std::env::var_os("AOC_CONFIG") {
+            Some(path) => Config::load(path.as_ref()).unwrap_or_else(|err| err.exit()),
+            None => Config::default(),
+        };
+
+        config
+            .params(S::DAY, S::PARAMS)
+            .unwrap_or_else(|err| err.exit())
+            .with_overrides(S::DAY, S::PARAMS, &day_overrides(S::DAY, &self.params))
+            .unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(2);
+            })
+    }
+}
+
+/// A day binary solves a single day: overrides not naming a day are meant for it, even when it doesn't have them.
+fn day_overrides(day: u8, overrides: &[ParamOverride]) -> Vec<ParamOverride> {
+    overrides
+        .iter()
+        .map(|param| ParamOverride {
+            day: param.day.or(Some(day)),
+            ..param.clone()
+        })
+        .collect()
+}
+
+impl<O: fmt::Display> Args<O> {
+    /// Open input, solve it and print answer, exits printing the error on failure.
+    /// `sentence` formats answer when it's printed as text.
+    pub fn run(
+        &self,
+        day: u8,
+        solve: impl FnOnce(&mut dyn BufRead, &O) -> Result<String, Error>,
+        sentence: impl FnOnce(&str) -> String,
+    ) {
+        init_tracing(self.verbosity);
+        let start = Instant::now();
+
+        let mut reader = self
+            .input
+            .open()
+            .unwrap_or_else(|err| Error::from(err).exit());
+        let answer = solve(&mut reader, &self.option)
+            .unwrap_or_else(|err| err.with_file(self.input.to_string()).exit());
+
+        self.print_answer(day, &answer, start.elapsed(), sentence(&answer));
+    }
+
+    /// Print answer of a day in requested format, `sentence` is what is printed in text format.
+    pub fn print_answer(
+        &self,
+        day: u8,
+        answer: impl ToString,
+        elapsed: Duration,
+        sentence: impl fmt::Display,
+    ) {
+        match self.format {
+            Format::Text => println!("{}", sentence),
+            Format::Json => {
+                let report = Report {
+                    day,
+                    part: self.option.to_string(),
+                    answer: answer.to_string(),
+                    elapsed,
+                    input: &self.input,
+                };
+                println!("{}", report.to_json());
+            }
+        }
+    }
+}
+
+impl Args<Part> {
+    /// Solve requested part of a day puzzle and print answer, see [`Args::run`].
+    /// Params are read from config file at `AOC_CONFIG` when it's set, then changed by `--param`.
+    pub fn run_puzzle<S: Solution>(&self, sentence: impl FnOnce(Part, &str) -> String) {
+        let params = self.params::<S>();
+
+        self.run(
+            S::DAY,
+            |mut reader, part| S::solve_with(&mut reader, *part, &params),
+            |answer| sentence(self.option, answer),
+        );
+    }
+
+    /// Parse args of current process where puzzle option is: A | B.
+    pub fn from_env_with_part() -> Self {
+        Self::from_env(PART_OPTION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{day_overrides, Args, ArgsError};
+    use crate::{
+        config::{Param, ParamOverride},
+        Format, Input, Params, Part,
+    };
+    use rstest::rstest;
+
+    fn to_args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[rstest]
+    #[case(&["input.txt", "A"], Part::A)]
+    #[case(&["input.txt", "B"], Part::B)]
+    fn should_parse_input_and_part(#[case] args: &[&str], #[case] expected: Part) {
+        let args = Args::<Part>::parse(to_args(args), "puzzle option").unwrap();
+
+        assert_eq!(Input::File("input.txt".into()), args.input);
+        assert_eq!(expected, args.option);
+        assert_eq!(Format::Text, args.format);
+    }
+
+    #[rstest]
+    #[case(&["input.txt", "A", "--format", "json"])]
+    #[case(&["--format", "json", "input.txt", "A"])]
+    #[case(&["input.txt", "--format", "json", "A"])]
+    fn should_parse_format(#[case] args: &[&str]) {
+        let args = Args::<Part>::parse(to_args(args), "puzzle option").unwrap();
+
+        assert_eq!(Input::File("input.txt".into()), args.input);
+        assert_eq!(Part::A, args.option);
+        assert_eq!(Format::Json, args.format);
+    }
+
+    #[rstest]
+    #[case(&["input.txt", "A"], 0)]
+    #[case(&["-v", "input.txt", "A"], 1)]
+    #[case(&["input.txt", "A", "--verbose"], 1)]
+    #[case(&["input.txt", "-vv", "A"], 2)]
+    #[case(&["-v", "input.txt", "-v", "A"], 2)]
+    fn should_parse_verbosity(#[case] args: &[&str], #[case] expected: u8) {
+        let args = Args::<Part>::parse(to_args(args), "puzzle option").unwrap();
+
+        assert_eq!(Input::File("input.txt".into()), args.input);
+        assert_eq!(expected, args.verbosity);
+    }
+
+    #[rstest]
+    #[case(&["-", "A"], Input::Stdin)]
+    #[case(&["--input-str", "A Y\nB X", "A"], Input::Str(String::from("A Y\nB X")))]
+    fn should_parse_other_inputs(#[case] args: &[&str], #[case] expected: Input) {
+        let args = Args::<Part>::parse(to_args(args), "puzzle option").unwrap();
+
+        assert_eq!(expected, args.input);
+        assert_eq!(Part::A, args.option);
+    }
+
+    #[test]
+    fn should_parse_params() {
+        let args = Args::<Part>::parse(
+            to_args(&[
+                "input.txt",
+                "--param",
+                "top=2",
+                "A",
+                "--param",
+                "day_7.disk_size=1",
+            ]),
+            "puzzle option",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![String::from("top=2"), String::from("day_7.disk_size=1")],
+            args.params
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case(&[], Ok(3))]
+    #[case(&["top=2"], Ok(2))]
+    #[case(&["day_1.top=2"], Ok(2))]
+    #[case(&["day_7.top=2"], Ok(3))]
+    #[case(&["size=2"], Err(String::from("Day 1: Unknown parameter: size, available ones are: top.")))]
+    fn should_apply_overrides_to_the_day(
+        #[case] overrides: &[&str],
+        #[case] expected: Result<u64, String>,
+    ) {
+        let params = &[Param {
+            name: "top",
+            default: 3,
+            min: 1,
+            about: "",
+        }];
+        let overrides = overrides
+            .iter()
+            .map(|param| param.parse().unwrap())
+            .collect::<Vec<ParamOverride>>();
+
+        assert_eq!(
+            expected,
+            Params::defaults(params)
+                .with_overrides(1, params, &day_overrides(1, &overrides))
+                .map(|params| params.get("top"))
+        );
+    }
+
+    #[rstest]
+    fn should_parse_custom_option() {
+        let args = Args::<usize>::parse(to_args(&["input.txt", "14"]), "marker length").unwrap();
+
+        assert_eq!(14, args.option);
+    }
+
+    #[rstest]
+    #[case(&[], ArgsError::MissingInput)]
+    #[case(&["--input-str"], ArgsError::MissingInput)]
+    #[case(&["input.txt", "A", "--format"], ArgsError::MissingOption("format"))]
+    #[case(&["input.txt"], ArgsError::MissingOption("puzzle option"))]
+    #[case(&["input.txt", "A", "B"], ArgsError::UnexpectedArg(String::from("B")))]
+    fn should_fail_parsing(#[case] args: &[&str], #[case] expected: ArgsError) {
+        assert_eq!(
+            expected,
+            Args::<Part>::parse(to_args(args), "puzzle option").unwrap_err()
+        );
+    }
+
+    #[rstest]
+    #[case(&["input.txt", "C"])]
+    #[case(&["input.txt", "A", "--format", "xml"])]
+    #[case(&["input.txt", "A", "--param", "top"])]
+    fn should_fail_on_invalid_option(#[case] args: &[&str]) {
+        let err = Args::<Part>::parse(to_args(args), "puzzle option").unwrap_err();
+
+        assert!(matches!(err, ArgsError::InvalidOption(_)));
+    }
+}
@@ -0,0 +1,558 @@
+//! Puzzle parameters, default inputs and parts, set in a config file:
+//!
+//! ```toml
+//! # Used by every day.
+//! input_dir = "inputs"   # inputs are: inputs/day_N.txt
+//! part = "A"
+//!
+//! [day_7]
+//! input = "day_7/src/test_inputs/test_input_1.txt"
+//! disk_size = 80_000_000
+//! ```
+//!
+//! Keys of `[day_N]` tables other than `input` and `part` are parameters of the day, see [`crate::Solution::PARAMS`].
+
+use serde::{
+    de::{IgnoredAny, MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    ops::Range,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use toml::Spanned;
+
+use crate::{Error, ParseError, Part};
+
+/// A number a puzzle depends on, eg: day 7 disk size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    /// Smallest valid value.
+    pub min: u64,
+    pub about: &'static str,
+}
+
+/// Value of every parameter of a day, see [`crate::Solution::PARAMS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, u64)>,
+}
+
+impl Params {
+    pub fn defaults(params: &[Param]) -> Self {
+        Self {
+            values: params
+                .iter()
+                .map(|param| (param.name, param.default))
+                .collect(),
+        }
+    }
+
+    /// Value of parameter `name`, panics when the day doesn't declare it.
+    pub fn get(&self, name: &str) -> u64 {
+        self.values
+            .iter()
+            .find(|(param, _)| *param == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("Unknown parameter: {}.", name))
+    }
+
+    /// Change parameter `name` of `params`, error tells why it can't be.
+    pub fn set(&mut self, params: &[Param], name: &str, value: u64) -> Result<(), String> {
+        let param = params
+            .iter()
+            .find(|param| param.name == name)
+            .ok_or_else(|| match params.is_empty() {
+                true => format!("Unknown parameter: {}, there is none.", name),
+                false => format!(
+                    "Unknown parameter: {}, available ones are: {}.",
+                    name,
+                    params
+                        .iter()
+                        .map(|param| param.name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            })?;
+
+        if value < param.min {
+            return Err(format!("{} has to be at least {}.", name, param.min));
+        }
+
+        for (param, current) in &mut self.values {
+            if *param == name {
+                *current = value;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply `overrides` meant for `day`: the ones naming it, and the ones not naming a day when it has that parameter.
+    pub fn with_overrides(
+        mut self,
+        day: u8,
+        params: &[Param],
+        overrides: &[ParamOverride],
+    ) -> Result<Self, String> {
+        for param in overrides {
+            let applies = match param.day {
+                Some(param_day) => param_day == day,
+                None => params.iter().any(|p| p.name == param.name),
+            };
+
+            if applies {
+                self.set(params, &param.name, param.value)
+                    .map_err(|err| format!("Day {}: {}", day, err))?;
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+/// A parameter set from the command line: `disk_size=80000000`, or `day_7.disk_size=80000000` for one day only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamOverride {
+    pub day: Option<u8>,
+    pub name: String,
+    pub value: u64,
+}
+
+impl FromStr for ParamOverride {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid parameter: \"{}\". Expected: <name>=<N> or day_<N>.<name>=<N>.",
+                s
+            )
+        };
+        let (key, value) = s.split_once('=').ok_or_else(invalid)?;
+        let (day, name) = match key.split_once('.') {
+            Some((section, name)) => (Some(section_day(section).ok_or_else(invalid)?), name),
+            None => (None, key),
+        };
+
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            day,
+            name: name.to_string(),
+            value: value.replace('_', "").parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl fmt::Display for ParamOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day_{}.", day)?;
+        }
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+/// Day of a `day_N` section name.
+fn section_day(section: &str) -> Option<u8> {
+    section.strip_prefix("day_")?.parse().ok()
+}
+
+/// Settings of a config file: global ones and `[day_N]` ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Config file, to point to it in errors.
+    file: Option<String>,
+    /// Relative paths are relative to this dir: the one of config file.
+    dir: PathBuf,
+    /// Text of config file, to point to invalid parameters.
+    text: String,
+    settings: Settings,
+}
+
+/// Global settings, then `[day_N]` tables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Settings {
+    input_dir: Option<PathBuf>,
+    part: Option<Part>,
+    days: BTreeMap<u8, DaySettings>,
+    /// First key that isn't a setting, to point to it.
+    unknown: Option<Spanned<String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct DaySettings {
+    input: Option<PathBuf>,
+    part: Option<Part>,
+    /// Global setting written in the table by mistake, to point to it.
+    input_dir: Option<Spanned<String>>,
+    /// Every other key is a parameter, spans point to it in errors.
+    params: Vec<(Spanned<String>, u64)>,
+}
+
+/// Tables are maps with keys of their own, so they are read key by key instead of being derived.
+impl<'de> Deserialize<'de> for Settings {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SettingsVisitor;
+
+        impl<'de> Visitor<'de> for SettingsVisitor {
+            type Value = Settings;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "global settings and [day_N] tables")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Settings, A::Error> {
+                let mut settings = Settings::default();
+
+                while let Some(key) = map.next_key::<Spanned<String>>()? {
+                    match (key.get_ref().as_str(), section_day(key.get_ref())) {
+                        ("input_dir", _) => settings.input_dir = Some(map.next_value()?),
+                        ("part", _) => settings.part = Some(map.next_value()?),
+                        (_, Some(day)) => {
+                            settings.days.insert(day, map.next_value()?);
+                        }
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                            settings.unknown.get_or_insert(key);
+                        }
+                    }
+                }
+
+                Ok(settings)
+            }
+        }
+
+        deserializer.deserialize_map(SettingsVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DaySettings {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct DayVisitor;
+
+        impl<'de> Visitor<'de> for DayVisitor {
+            type Value = DaySettings;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a day table, eg: [day_7]")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DaySettings, A::Error> {
+                let mut settings = DaySettings::default();
+
+                while let Some(key) = map.next_key::<Spanned<String>>()? {
+                    match key.get_ref().as_str() {
+                        "input" => settings.input = Some(map.next_value()?),
+                        "part" => settings.part = Some(map.next_value()?),
+                        "input_dir" => {
+                            map.next_value::<IgnoredAny>()?;
+                            settings.input_dir = Some(key);
+                        }
+                        _ => settings.params.push((key, map.next_value()?)),
+                    }
+                }
+
+                Ok(settings)
+            }
+        }
+
+        deserializer.deserialize_map(DayVisitor)
+    }
+}
+
+impl Config {
+    /// Read config file at `path`.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| Error::Io(format!("{}: {}", path.display(), err)))?;
+        let mut config =
+            Self::parse(&text).map_err(|err| err.with_file(path.display().to_string()))?;
+        config.file = Some(path.display().to_string());
+        config.dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        Ok(config)
+    }
+
+    /// Config from its text, relative paths are relative to current dir.
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let settings: Settings = toml::from_str(text).map_err(|err: toml::de::Error| {
+            // Toml messages start lower case.
+            let mut message = err.message().trim_end_matches('.').to_string();
+            if let Some(first) = message.get(..1) {
+                message.replace_range(..1, &first.to_uppercase());
+            }
+
+            error_at(text, err.span().unwrap_or(0..0), format!("{}.", message))
+        })?;
+
+        if let Some(key) = &settings.unknown {
+            return Err(error_at(
+                text,
+                key.span(),
+                format!(
+                    "Unknown global setting: {}, available ones are: input_dir, part. Parameters go in a day table, eg: [day_7].",
+                    key.get_ref()
+                ),
+            )
+            .into());
+        }
+        if let Some(key) = settings
+            .days
+            .values()
+            .find_map(|day| day.input_dir.as_ref())
+        {
+            return Err(error_at(
+                text,
+                key.span(),
+                "input_dir is a global setting, it goes before any table.",
+            )
+            .into());
+        }
+
+        Ok(Self {
+            text: text.to_string(),
+            settings,
+            ..Self::default()
+        })
+    }
+
+    fn day(&self, day: u8) -> Option<&DaySettings> {
+        self.settings.days.get(&day)
+    }
+
+    /// Input of `day`: its `input`, else `day_N.txt` in global `input_dir`.
+    pub fn input(&self, day: u8) -> Option<PathBuf> {
+        match self.day(day).and_then(|settings| settings.input.as_ref()) {
+            Some(path) => Some(self.dir.join(path)),
+            None => self
+                .settings
+                .input_dir
+                .as_ref()
+                .map(|dir| self.dir.join(dir).join(format!("day_{}.txt", day))),
+        }
+    }
+
+    /// Part to solve for `day` when none is asked: its `part`, else global one.
+    pub fn part(&self, day: u8) -> Option<Part> {
+        self.day(day)
+            .and_then(|settings| settings.part)
+            .or(self.settings.part)
+    }
+
+    /// Parameters of `day`: `params` defaults, changed by `[day_N]` table.
+    pub fn params(&self, day: u8, params: &[Param]) -> Result<Params, Error> {
+        let mut values = Params::defaults(params);
+
+        for (name, value) in self.day(day).into_iter().flat_map(|day| &day.params) {
+            values.set(params, name.get_ref(), *value).map_err(|err| {
+                let err = Error::from(error_at(&self.text, name.span(), err));
+
+                match &self.file {
+                    Some(file) => err.with_file(file.clone()),
+                    None => err,
+                }
+            })?;
+        }
+
+        Ok(values)
+    }
+}
+
+/// Error pointing to bytes `span` of `text`.
+fn error_at(text: &str, span: Range<usize>, message: impl Into<String>) -> ParseError {
+    let line_start = text[..span.start].rfind('\n').map_or(0, |idx| idx + 1);
+    let line_end = text[span.start..]
+        .find('\n')
+        .map_or(text.len(), |idx| span.start + idx);
+    ParseError::new(
+        message,
+        text[..span.start].matches('\n').count() + 1,
+        text[line_start..line_end].trim_end_matches('\r'),
+        text[line_start..span.start].chars().count() + 1,
+        text[span.start..span.end.clamp(span.start, line_end)]
+            .chars()
+            .count(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Param, ParamOverride, Params};
+    use crate::{Error, Part};
+    use rstest::rstest;
+    use std::path::PathBuf;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "disk_size",
+            default: 70_000_000,
+            min: 1,
+            about: "Size of the disk.",
+        },
+        Param {
+            name: "required_space",
+            default: 30_000_000,
+            min: 0,
+            about: "Free space needed.",
+        },
+    ];
+
+    const CONFIG: &str = r#"
+# Every day.
+input_dir = "inputs"
+part = "B"
+
+[day_7]   # No space left.
+input = "day_7/src/test_inputs/test_input_1.txt"
+disk_size = 80_000_000
+part = "a"
+
+[day_1]
+"#;
+
+    #[test]
+    fn should_read_inputs_and_parts() {
+        let config = Config::parse(CONFIG).unwrap();
+
+        assert_eq!(
+            Some(PathBuf::from("day_7/src/test_inputs/test_input_1.txt")),
+            config.input(7)
+        );
+        assert_eq!(Some(PathBuf::from("inputs/day_1.txt")), config.input(1));
+        assert_eq!(Some(Part::A), config.part(7));
+        assert_eq!(Some(Part::B), config.part(3));
+        assert_eq!(None, Config::default().input(7));
+    }
+
+    #[test]
+    fn should_change_params() {
+        let params = Config::parse(CONFIG).unwrap().params(7, PARAMS).unwrap();
+
+        assert_eq!(80_000_000, params.get("disk_size"));
+        assert_eq!(30_000_000, params.get("required_space"));
+        assert_eq!(
+            Params::defaults(PARAMS),
+            Config::default().params(7, PARAMS).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "[day_7]\nsize = 3",
+        7,
+        "Unknown parameter: size, available ones are: disk_size, required_space.",
+        2,
+        1
+    )]
+    #[case("[day_7]\n\ndisk_size = 0", 7, "disk_size has to be at least 1.", 3, 1)]
+    #[case("[day_2]\ntop = 3", 2, "Unknown parameter: top, there is none.", 2, 1)]
+    fn should_point_to_invalid_params(
+        #[case] text: &str,
+        #[case] day: u8,
+        #[case] message: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+    ) {
+        let params = match day {
+            7 => PARAMS,
+            _ => &[],
+        };
+
+        match Config::parse(text).unwrap().params(day, params) {
+            Err(Error::Parse(err)) => assert_eq!(
+                (message, line, column),
+                (err.message.as_str(), err.line, err.column)
+            ),
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+
+    #[rstest]
+    #[case("top = 3", 1, 1)]
+    #[case("part = \"A\"\n  top = 3", 2, 3)]
+    #[case("[day_7]\npart = \"C\"", 2, 8)]
+    #[case("[day_7]\ninput = 7", 2, 9)]
+    #[case("[day_7]\ndisk_size = \"big\"", 2, 13)]
+    #[case("[days]", 1, 2)]
+    #[case("[day_7]\ninput_dir = \"inputs\"", 2, 1)]
+    #[case("input = \"input.txt\"", 1, 1)]
+    #[case("[day_7", 1, 7)]
+    #[case("[day_7]\ndisk_size 3", 2, 11)]
+    #[case("[day_7]\ndisk_size = -3", 2, 13)]
+    #[case("[day_7]\ndisk_size = 3 4", 2, 15)]
+    #[case("[day_7]\ndisk_size = _3", 2, 13)]
+    #[case("input_dir = \"inputs", 1, 20)]
+    fn should_fail_parsing(#[case] text: &str, #[case] line: usize, #[case] column: usize) {
+        match Config::parse(text) {
+            Err(Error::Parse(err)) => assert_eq!((line, column), (err.line, err.column), "{}", err),
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+
+    #[rstest]
+    #[case("disk_size=1", None, "disk_size", 1, "disk_size=1")]
+    #[case(
+        "day_7.disk_size=80_000_000",
+        Some(7),
+        "disk_size",
+        80_000_000,
+        "day_7.disk_size=80000000"
+    )]
+    fn should_parse_override(
+        #[case] text: &str,
+        #[case] day: Option<u8>,
+        #[case] name: &str,
+        #[case] value: u64,
+        #[case] display: &str,
+    ) {
+        let expected = ParamOverride {
+            day,
+            name: name.to_string(),
+            value,
+        };
+
+        assert_eq!(expected, text.parse().unwrap());
+        assert_eq!(display, expected.to_string());
+    }
+
+    #[rstest]
+    #[case("disk_size")]
+    #[case("disk_size=-1")]
+    #[case("=1")]
+    #[case("seven.disk_size=1")]
+    fn should_not_parse_override(#[case] text: &str) {
+        assert!(text.parse::<ParamOverride>().is_err());
+    }
+
+    #[test]
+    fn should_override_config_with_command_line() {
+        let config = Config::parse(CONFIG).unwrap();
+        let overrides = ["required_space=1", "day_1.disk_size=2", "top=3"]
+            .map(|o| o.parse::<ParamOverride>().unwrap());
+
+        let params = config
+            .params(7, PARAMS)
+            .unwrap()
+            .with_overrides(7, PARAMS, &overrides)
+            .unwrap();
+
+        assert_eq!(80_000_000, params.get("disk_size"));
+        assert_eq!(1, params.get("required_space"));
+        assert_eq!(
+            Err(String::from("Day 7: disk_size has to be at least 1.")),
+            Params::defaults(PARAMS).with_overrides(7, PARAMS, &["disk_size=0".parse().unwrap()])
+        );
+        assert!(Params::defaults(PARAMS)
+            .with_overrides(7, PARAMS, &["day_7.top=3".parse().unwrap()])
+            .is_err());
+    }
+}
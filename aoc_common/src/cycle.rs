@@ -0,0 +1,247 @@
+//! Long simulations: a state is stepped until it repeats an earlier one, then the [`Cycle`] it entered
+//! gives the state after any number of steps without running them. Eg: a billion steps of a counter modulo 7:
+//! ```
+//! use aoc_common::cycle::nth;
+//!
+//! assert_eq!(1_000_000_000 % 7, nth(0, 1_000_000_000, |n| (n + 1) % 7));
+//! ```
+//! [`Memo`] computes a function once per argument, eg: a step visiting the same states again.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Steps after which states repeat: state of step `start + length` is the state of step `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Earliest step with the same state as step `n`.
+    pub fn index(&self, n: usize) -> usize {
+        match n.checked_sub(self.start) {
+            Some(offset) => self.start + offset % self.length,
+            None => n,
+        }
+    }
+
+    /// Number of whole cycles run from `start` to step `n`.
+    pub fn repeats(&self, n: usize) -> usize {
+        n.saturating_sub(self.start) / self.length
+    }
+}
+
+/// States of a simulation, from the initial one until the first one repeating an earlier one (included).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History<S> {
+    pub states: Vec<S>,
+    /// Missing when no state repeated within the steps run.
+    pub cycle: Option<Cycle>,
+}
+
+impl<S> History<S> {
+    /// State after `n` steps, `None` when it's further than steps run and no cycle was found.
+    /// With a key (see [`run`]) it's a state with the same key, eg: same shape but not the same height.
+    pub fn nth(&self, n: usize) -> Option<&S> {
+        match self.cycle {
+            Some(cycle) => self.states.get(cycle.index(n)),
+            None => self.states.get(n),
+        }
+    }
+
+    /// Value after `n` steps of a quantity growing by the same amount on each cycle, eg: height of a tower of rocks.
+    /// `None` when it's further than steps run and no cycle was found.
+    pub fn extrapolate(&self, n: usize, value: impl Fn(&S) -> i64) -> Option<i64> {
+        let Some(cycle) = self.cycle else {
+            return self.states.get(n).map(value);
+        };
+        let growth =
+            value(&self.states[cycle.start + cycle.length]) - value(&self.states[cycle.start]);
+
+        Some(value(&self.states[cycle.index(n)]) + cycle.repeats(n) as i64 * growth)
+    }
+}
+
+/// Step `initial` state at most `limit` times, until a state has the `key` of an earlier one.
+/// Key is what decides future states, eg: the state itself, or the top rows of a tower without its height.
+pub fn run<S, K>(
+    initial: S,
+    limit: usize,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> History<S>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::from([(key(&initial), 0)]);
+    let mut states = vec![initial];
+
+    while states.len() <= limit {
+        let next = step(states.last().unwrap());
+        let next_key = key(&next);
+        states.push(next);
+
+        if let Some(&start) = seen.get(&next_key) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - 1 - start,
+            };
+            tracing::debug!(start, length = cycle.length, "cycle");
+
+            return History {
+                states,
+                cycle: Some(cycle),
+            };
+        }
+        seen.insert(next_key, states.len() - 1);
+    }
+
+    History {
+        states,
+        cycle: None,
+    }
+}
+
+/// State after `n` steps of `initial`, running steps only until states repeat.
+pub fn nth<S>(initial: S, n: usize, step: impl FnMut(&S) -> S) -> S
+where
+    S: Clone + Eq + Hash,
+{
+    run(initial, n, step, S::clone)
+        .nth(n)
+        .expect("Steps are run up to n, or until a cycle.")
+        .clone()
+}
+
+/// Values of a function computed once per argument.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    /// Number of values found already computed.
+    pub hits: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            hits: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Value of `key`, computed by `compute` the first time only.
+    pub fn get(&mut self, key: K, compute: impl FnOnce(&K) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        let value = compute(&key);
+        self.values.insert(key, value.clone());
+        value
+    }
+
+    /// Number of values computed.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{nth, run, Cycle, Memo};
+    use rstest::rstest;
+
+    /// 3 -> 9 -> 81 -> 61 -> 21 -> 41 -> 81: a tail of 2 then a cycle of 4.
+    fn square(n: &u64) -> u64 {
+        n * n % 100
+    }
+
+    #[test]
+    fn should_find_cycle() {
+        let history = run(3, 1000, square, |n| *n);
+
+        assert_eq!(
+            Some(Cycle {
+                start: 2,
+                length: 4
+            }),
+            history.cycle
+        );
+        assert_eq!(vec![3, 9, 81, 61, 21, 41, 81], history.states);
+    }
+
+    #[rstest]
+    #[case(0, 3)]
+    #[case(1, 9)]
+    #[case(6, 81)]
+    #[case(7, 61)]
+    #[case(1_000_000_001, 41)]
+    fn should_skip_to_nth_state(#[case] n: usize, #[case] expected: u64) {
+        let brute_force = (0..n.min(100)).fold(3, |state, _| square(&state));
+
+        assert_eq!(expected, nth(3, n, square));
+        if n < 100 {
+            assert_eq!(brute_force, expected);
+        }
+    }
+
+    #[test]
+    fn should_stop_at_limit() {
+        let history = run(0u64, 5, |n| n + 1, |n| *n);
+
+        assert_eq!(None, history.cycle);
+        assert_eq!(Some(&5), history.nth(5));
+        assert_eq!(None, history.nth(6));
+        assert_eq!(7, nth(0u64, 7, |n| n + 1));
+    }
+
+    #[test]
+    fn should_extrapolate_growing_values() {
+        // A height growing by 3 each cycle of 2 steps, after a tail of 1 step: 0, 5, 6, 8, 9, 11...
+        let step = |(phase, height): &(u8, i64)| match phase {
+            0 => (1, height + 5),
+            1 => (2, height + 1),
+            _ => (1, height + 2),
+        };
+        let history = run((0, 0), 1000, step, |(phase, _)| *phase);
+        let brute_force = (0..101).fold((0, 0), |state, _| step(&state)).1;
+
+        assert_eq!(
+            Some(Cycle {
+                start: 1,
+                length: 2
+            }),
+            history.cycle
+        );
+        assert_eq!(Some(brute_force), history.extrapolate(101, |(_, h)| *h));
+        assert_eq!(Some(5), history.extrapolate(1, |(_, h)| *h));
+    }
+
+    #[test]
+    fn should_compute_once() {
+        let mut memo = Memo::new();
+        let mut computed = 0;
+
+        for n in [3, 9, 3, 3] {
+            memo.get(n, |n| {
+                computed += 1;
+                square(n)
+            });
+        }
+
+        assert_eq!(2, computed);
+        assert_eq!(2, memo.hits);
+        assert_eq!(81, memo.get(9, |_| unreachable!()));
+    }
+}
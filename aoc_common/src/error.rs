@@ -0,0 +1,200 @@
+use std::{fmt, io, process};
+
+/// Error returned by every day parser and solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Input can't be read.
+    Io(String),
+    /// Input is malformed.
+    Parse(ParseError),
+    /// Input is well formed, but puzzle has no answer for it.
+    NoAnswer(String),
+}
+
+/// Points to the offending text in puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Input name, set by callers that know where input comes from.
+    pub file: Option<String>,
+    /// Line number, starting from 1.
+    pub line: usize,
+    /// Column of first offending char, starting from 1.
+    pub column: usize,
+    /// Offending text, may be empty when something is missing.
+    pub text: String,
+    /// Whole line containing offending text.
+    pub line_text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Create an error pointing to `width` chars of `line_text` starting from `column` (1-based).
+    pub fn new(
+        message: impl Into<String>,
+        line: usize,
+        line_text: &str,
+        column: usize,
+        width: usize,
+    ) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            text: line_text
+                .chars()
+                .skip(column.saturating_sub(1))
+                .take(width)
+                .collect(),
+            line_text: line_text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Create an error pointing to the whole line.
+    pub fn whole_line(message: impl Into<String>, line: usize, line_text: &str) -> Self {
+        Self::new(message, line, line_text, 1, line_text.chars().count())
+    }
+
+    /// Same offending text with a message that says more about what was expected there.
+    pub fn with_message(self, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..self
+        }
+    }
+
+    /// Render a diagnostic with a caret under the offending text. Eg:
+    /// ```text
+    /// error: Only: A,B,C,X,Y,Z are allowed.
+    ///  --> input.txt:3:1
+    ///   |
+    /// 3 | D Y
+    ///   | ^
+    /// ```
+    pub fn render(&self) -> String {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.message,
+            gutter,
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            gutter,
+            line_number,
+            self.line_text,
+            gutter,
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.text.chars().count().max(1)),
+        )
+    }
+}
+
+impl Error {
+    /// Attach input name to parse errors.
+    pub fn with_file(self, file: impl Into<String>) -> Self {
+        match self {
+            Error::Parse(err) => Error::Parse(ParseError {
+                file: Some(file.into()),
+                ..err
+            }),
+            other => other,
+        }
+    }
+
+    /// Render error for the terminal, parse errors get a caret under the offending text.
+    pub fn render(&self) -> String {
+        match self {
+            Error::Parse(err) => err.render(),
+            other => format!("error: {}", other),
+        }
+    }
+
+    /// Print rendered error and exit, to be used by binaries.
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self.render());
+        process::exit(1)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.message
+        )
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "Unable to read input. {}", err),
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::NoAnswer(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err.to_string())
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ParseError};
+
+    #[test]
+    fn should_point_to_offending_text() {
+        let err = ParseError::new("Not a number.", 3, "12-x4,5-6", 4, 2);
+
+        assert_eq!("x4", err.text);
+        assert_eq!(
+            "error: Not a number.\n --> <input>:3:4\n  |\n3 | 12-x4,5-6\n  |    ^^",
+            err.render()
+        );
+    }
+
+    #[test]
+    fn should_render_caret_for_missing_text() {
+        let err = ParseError::new("Missing figure.", 12, "A", 3, 1);
+
+        assert_eq!("", err.text);
+        assert_eq!(
+            "error: Missing figure.\n  --> <input>:12:3\n   |\n12 | A\n   |   ^",
+            err.render()
+        );
+    }
+
+    #[test]
+    fn should_attach_file() {
+        let err = Error::from(ParseError::whole_line("Bad line.", 1, "abc")).with_file("input.txt");
+
+        assert_eq!("input.txt:1:1: Bad line.", err.to_string());
+        assert!(err.render().contains(" --> input.txt:1:1"));
+        assert!(err.render().ends_with("| ^^^"));
+    }
+
+    #[test]
+    fn should_render_other_errors() {
+        let err = Error::NoAnswer(String::from("Unable to find marker.")).with_file("input.txt");
+
+        assert_eq!("error: Unable to find marker.", err.render());
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::PathBuf,
+};
+
+/// Where puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// A file on disk.
+    File(PathBuf),
+    /// Standard input of current process.
+    Stdin,
+    /// An inline string, useful for tests and shell one-liners.
+    Str(String),
+}
+
+impl Input {
+    /// Input from a cli arg: `-` is stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Open input as a buffered reader.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Input::File(path) => {
+                let file = File::open(path).map_err(|err| {
+                    io::Error::new(
+                        err.kind(),
+                        format!("Unable to find file: {}. {}", path.display(), err),
+                    )
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Input::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            Input::Str(content) => Ok(Box::new(Cursor::new(content.clone().into_bytes()))),
+        }
+    }
+
+    /// Read whole input into a string.
+    pub fn read_to_string(&self) -> io::Result<String> {
+        let mut content = String::new();
+        self.open()?.read_to_string(&mut content)?;
+        Ok(content)
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Str(_) => write!(f, "<string>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Input;
+    use rstest::rstest;
+    use std::io::BufRead;
+
+    #[rstest]
+    #[case("-", Input::Stdin)]
+    #[case("input.txt", Input::File("input.txt".into()))]
+    #[case("./-", Input::File("./-".into()))]
+    fn should_get_input_from_arg(#[case] arg: &str, #[case] expected: Input) {
+        assert_eq!(expected, Input::from_arg(arg));
+    }
+
+    #[test]
+    fn should_read_lines_from_string() {
+        let input = Input::Str(String::from("1000\n2000\n\n3000"));
+        let lines = input
+            .open()
+            .unwrap()
+            .lines()
+            .map(|l| l.unwrap())
+            .collect::<Vec<String>>();
+
+        assert_eq!(vec!["1000", "2000", "", "3000"], lines);
+    }
+
+    #[test]
+    fn should_read_file() {
+        let input = Input::File("./src/lib.rs".into());
+
+        assert!(input.read_to_string().unwrap().contains("mod input;"));
+    }
+
+    #[test]
+    fn should_fail_on_missing_file() {
+        let input = Input::File("./this/file/does/not/exist.txt".into());
+        let err = input.open().err().unwrap();
+
+        assert!(err.to_string().contains("Unable to find file"));
+    }
+}
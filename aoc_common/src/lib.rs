@@ -0,0 +1,25 @@
+//! Shared helpers used by every day of [Advent of code 2022](https://adventofcode.com/2022),
+//! around the [`Solution`] trait each day implements.
+
+mod args;
+pub mod config;
+pub mod cycle;
+mod error;
+mod input;
+pub mod parse;
+mod part;
+mod profile;
+mod report;
+pub mod search;
+mod solution;
+mod trace;
+
+pub use args::{usage, Args, ArgsError, PART_OPTION};
+pub use config::{Param, Params};
+pub use error::{Error, ParseError};
+pub use input::Input;
+pub use part::Part;
+pub use profile::{format_duration, measure, CountingAllocator, Phase};
+pub use report::{json_string, Format, Report};
+pub use solution::{Entry, Profile, Solution};
+pub use trace::init_tracing;
@@ -0,0 +1,519 @@
+//! Small parser combinators for puzzle inputs. Parsers work on a [`Cursor`] over a line,
+//! on failure they leave the cursor where it was and return a [`ParseError`] pointing to the offending text.
+//! Eg: "move 1 from 2 to 3":
+//! ```
+//! use aoc_common::parse::Cursor;
+//!
+//! let mut cursor = Cursor::new("move 1 from 2 to 3", 1);
+//! cursor.tag("move ")?;
+//! let count = cursor.integer::<u8>()?;
+//! cursor.tag(" from ")?;
+//! let from = cursor.integer::<u8>()?;
+//! cursor.tag(" to ")?;
+//! let to = cursor.integer::<u8>()?;
+//! cursor.end()?;
+//!
+//! assert_eq!((1, 2, 3), (count, from, to));
+//! # Ok::<(), aoc_common::ParseError>(())
+//! ```
+
+use crate::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+/// Where a parsed value is in its line: `width` chars from `column` (1-based).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub column: usize,
+    pub width: usize,
+}
+
+/// Position in a line of input, parsers move it forward.
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: &'a str,
+    line_number: usize,
+    // Byte offset of what is left to parse.
+    position: usize,
+}
+
+/// A shell command, eg: "$ cd a" is named "cd" with argument "a".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command<'a> {
+    pub name: &'a str,
+    pub argument: Option<&'a str>,
+    /// Name and argument.
+    pub span: Span,
+}
+
+/// Lines of input between blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// Line number of the first line, starting from 1.
+    pub line_number: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Cursor<'a> {
+    /// Cursor at the start of `line`, `line_number` starts from 1.
+    pub fn new(line: &'a str, line_number: usize) -> Self {
+        Self {
+            line,
+            line_number,
+            position: 0,
+        }
+    }
+
+    /// What is left to parse.
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.position..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    /// Column (1-based) of what is left to parse.
+    pub fn column(&self) -> usize {
+        self.line[..self.position].chars().count() + 1
+    }
+
+    /// Error pointing to `width` chars from cursor.
+    pub fn error(&self, message: impl Into<String>, width: usize) -> ParseError {
+        self.error_at(
+            Span {
+                column: self.column(),
+                width,
+            },
+            message,
+        )
+    }
+
+    /// Error pointing to a value parsed before, see: [`Cursor::spanned`].
+    pub fn error_at(&self, span: Span, message: impl Into<String>) -> ParseError {
+        ParseError::new(
+            message,
+            self.line_number,
+            self.line,
+            span.column,
+            span.width,
+        )
+    }
+
+    /// Error pointing to the whole line.
+    pub fn whole_line(&self, message: impl Into<String>) -> ParseError {
+        ParseError::whole_line(message, self.line_number, self.line)
+    }
+
+    /// Run `parser` returning where its value is, eg: to point to a number that is parsed but not valid.
+    pub fn spanned<T>(
+        &mut self,
+        parser: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<(T, Span), ParseError> {
+        let column = self.column();
+        let value = parser(self)?;
+
+        Ok((
+            value,
+            Span {
+                column,
+                width: self.column() - column,
+            },
+        ))
+    }
+
+    /// Run `parser`, moving cursor back where it was when it fails.
+    fn attempt<T>(
+        &mut self,
+        parser: impl FnOnce(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        let position = self.position;
+
+        parser(self).inspect_err(|_| self.position = position)
+    }
+
+    fn advance(&mut self, bytes: usize) -> &'a str {
+        let parsed = &self.rest()[..bytes];
+        self.position += bytes;
+        parsed
+    }
+
+    /// Width of the word under cursor, so that errors point to all of it: "x" in "6-x".
+    fn token_width(&self) -> usize {
+        match self
+            .rest()
+            .chars()
+            .take_while(|c| c.is_alphanumeric())
+            .count()
+        {
+            0 => self.rest().chars().take(1).count(),
+            width => width,
+        }
+    }
+
+    /// Exactly `tag`.
+    pub fn tag(&mut self, tag: &str) -> Result<(), ParseError> {
+        match self.rest().starts_with(tag) {
+            true => {
+                self.advance(tag.len());
+                Ok(())
+            }
+            false => Err(self.error(format!("Expected: \"{}\".", tag), tag.chars().count())),
+        }
+    }
+
+    /// Any char.
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        let c = self
+            .rest()
+            .chars()
+            .next()
+            .ok_or_else(|| self.error("Expected a char.", 0))?;
+        self.advance(c.len_utf8());
+
+        Ok(c)
+    }
+
+    /// Longest run of chars matching `predicate`, may be empty.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let bytes = self
+            .rest()
+            .char_indices()
+            .find(|(_, c)| !predicate(*c))
+            .map_or(self.rest().len(), |(idx, _)| idx);
+
+        self.advance(bytes)
+    }
+
+    /// Non empty run of chars up to a whitespace.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        match self.take_while(|c| !c.is_whitespace()) {
+            "" => Err(self.error("Expected a word.", self.rest().chars().take(1).count())),
+            word => Ok(word),
+        }
+    }
+
+    /// Digits with an optional leading minus: "-12".
+    pub fn integer<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.attempt(|cursor| {
+            let column = cursor.column();
+            let sign = match cursor.rest().starts_with('-') {
+                true => 1,
+                false => 0,
+            };
+            let digits = cursor.rest()[sign..]
+                .bytes()
+                .take_while(u8::is_ascii_digit)
+                .count();
+
+            if digits == 0 {
+                return Err(cursor.error("Expected a number.", cursor.token_width()));
+            }
+
+            let number = cursor.advance(sign + digits);
+
+            number.parse::<T>().map_err(|err| {
+                cursor.error_at(
+                    Span {
+                        column,
+                        width: number.len(),
+                    },
+                    format!("Invalid number: {}.", err),
+                )
+            })
+        })
+    }
+
+    /// Two integers separated by a dash: "2-8".
+    pub fn range<T>(&mut self) -> Result<(T, T), ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.attempt(|cursor| {
+            let (start, span) = cursor.spanned(Self::integer)?;
+
+            cursor
+                .tag("-")
+                .map_err(|_| cursor.error_at(span, "Expected a range like: 2-8."))?;
+
+            Ok((start, cursor.integer()?))
+        })
+    }
+
+    /// One or more `item` separated by `separator`: "1,2,3".
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.attempt(|cursor| {
+            let mut items = vec![item(cursor)?];
+
+            while cursor.tag(separator).is_ok() {
+                items.push(item(cursor)?);
+            }
+
+            Ok(items)
+        })
+    }
+
+    /// A line starting with "$ ", None otherwise. Consumes the whole line.
+    pub fn command(&mut self) -> Option<Command<'a>> {
+        self.tag("$ ").ok()?;
+
+        let span = Span {
+            column: self.column(),
+            width: self.rest().chars().count(),
+        };
+        let name = self.take_while(|c| c != ' ');
+        let argument = self.tag(" ").ok().map(|_| self.advance(self.rest().len()));
+
+        Some(Command {
+            name,
+            argument,
+            span,
+        })
+    }
+
+    /// Nothing left to parse.
+    pub fn end(&self) -> Result<(), ParseError> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.error("Unexpected text.", self.rest().chars().count())),
+        }
+    }
+}
+
+impl<'a> Block<'a> {
+    /// Lines with their number.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        (self.line_number..).zip(self.lines.iter().copied())
+    }
+
+    /// Rows of a rectangular grid where each char is a cell, converted by `cell`: "30\n25" with digits as cells.
+    pub fn grid<T>(&self, cell: impl Fn(char) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError> {
+        let width = self.lines.first().map_or(0, |line| line.chars().count());
+
+        self.numbered_lines()
+            .map(|(line_number, line)| {
+                let cursor = Cursor::new(line, line_number);
+
+                if line.chars().count() != width {
+                    return Err(cursor.whole_line(format!("Expected a row of {} cells.", width)));
+                }
+
+                line.chars()
+                    .enumerate()
+                    .map(|(idx, c)| {
+                        cell(c).ok_or_else(|| {
+                            ParseError::new("Unexpected cell.", line_number, line, idx + 1, 1)
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Groups of lines separated by blank lines, many blank lines in a row count as one.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut previous_blank = true;
+
+    for (idx, line) in input.lines().enumerate() {
+        match (line.is_empty(), previous_blank) {
+            (true, _) => {}
+            (false, true) => blocks.push(Block {
+                line_number: idx + 1,
+                lines: vec![line],
+            }),
+            (false, false) => blocks.last_mut().unwrap().lines.push(line),
+        }
+        previous_blank = line.is_empty();
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocks, Block, Command, Cursor, Span};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("12", 12)]
+    #[case("-3 apples", -3)]
+    #[case("007", 7)]
+    fn should_parse_integer(#[case] line: &str, #[case] expected: i32) {
+        assert_eq!(expected, Cursor::new(line, 1).integer::<i32>().unwrap());
+    }
+
+    #[rstest]
+    #[case("x", 1, "x")]
+    #[case("abc file.txt", 1, "abc")]
+    #[case("", 1, "")]
+    #[case("-", 1, "-")]
+    #[case("256", 1, "256")]
+    fn should_point_to_invalid_integer(
+        #[case] line: &str,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        let mut cursor = Cursor::new(line, 4);
+        let err = cursor.integer::<u8>().unwrap_err();
+
+        assert_eq!((4, column, text), (err.line, err.column, err.text.as_str()));
+        // Cursor doesn't move on failure.
+        assert_eq!(line, cursor.rest());
+    }
+
+    #[rstest]
+    #[case("2-8", (2, 8))]
+    #[case("15-15,1-2", (15, 15))]
+    fn should_parse_range(#[case] line: &str, #[case] expected: (u8, u8)) {
+        assert_eq!(expected, Cursor::new(line, 1).range::<u8>().unwrap());
+    }
+
+    #[rstest]
+    #[case("6", 1, "6")]
+    #[case("6-x", 3, "x")]
+    #[case("12,3", 1, "12")]
+    fn should_point_to_invalid_range(
+        #[case] line: &str,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        let err = Cursor::new(line, 1).range::<u8>().unwrap_err();
+
+        assert_eq!((column, text), (err.column, err.text.as_str()));
+    }
+
+    #[rstest]
+    #[case("1,2,3", vec![1, 2, 3])]
+    #[case("1", vec![1])]
+    #[case("1,2;3", vec![1, 2])]
+    fn should_parse_separated(#[case] line: &str, #[case] expected: Vec<u8>) {
+        assert_eq!(
+            expected,
+            Cursor::new(line, 1)
+                .separated(",", Cursor::integer)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_parse_a_line_with_many_parsers() {
+        let mut cursor = Cursor::new("move 12 from 2 to 3", 1);
+
+        cursor.tag("move ").unwrap();
+        assert_eq!(12u8, cursor.integer().unwrap());
+        cursor.tag(" from ").unwrap();
+        assert_eq!(
+            (
+                2u8,
+                Span {
+                    column: 14,
+                    width: 1
+                }
+            ),
+            cursor.spanned(Cursor::integer).unwrap()
+        );
+        assert_eq!(" to ", cursor.take_while(|c| !c.is_ascii_digit()));
+        assert_eq!("3", cursor.word().unwrap());
+        assert!(cursor.end().is_ok());
+    }
+
+    #[rstest]
+    #[case("shift 1", "move ", 1, "shift")]
+    #[case("mov", "move ", 1, "mov")]
+    #[case("A", "A ", 1, "A")]
+    fn should_point_to_missing_tag(
+        #[case] line: &str,
+        #[case] tag: &str,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        let err = Cursor::new(line, 1).tag(tag).unwrap_err();
+
+        assert_eq!((column, text), (err.column, err.text.as_str()));
+    }
+
+    #[test]
+    fn should_point_to_unexpected_text() {
+        let mut cursor = Cursor::new("B X Z", 1);
+
+        cursor.char().unwrap();
+        cursor.tag(" ").unwrap();
+        cursor.char().unwrap();
+
+        let err = cursor.end().unwrap_err();
+        assert_eq!((4, " Z"), (err.column, err.text.as_str()));
+    }
+
+    #[rstest]
+    #[case("$ cd /", Some(Command { name: "cd", argument: Some("/"), span: Span { column: 3, width: 4 } }))]
+    #[case("$ ls", Some(Command { name: "ls", argument: None, span: Span { column: 3, width: 2 } }))]
+    #[case("dir a", None)]
+    fn should_parse_command(#[case] line: &str, #[case] expected: Option<Command>) {
+        assert_eq!(expected, Cursor::new(line, 1).command());
+    }
+
+    #[test]
+    fn should_split_blocks_on_blank_lines() {
+        let blocks = blocks("1\n2\n\n3\n\n\n4\n");
+
+        assert_eq!(
+            vec![
+                Block {
+                    line_number: 1,
+                    lines: vec!["1", "2"]
+                },
+                Block {
+                    line_number: 4,
+                    lines: vec!["3"]
+                },
+                Block {
+                    line_number: 7,
+                    lines: vec!["4"]
+                },
+            ],
+            blocks
+        );
+        assert_eq!(
+            vec![(1, "1"), (2, "2")],
+            blocks[0].numbered_lines().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_parse_grid() {
+        let block = &blocks("\n30\n25")[0];
+
+        assert_eq!(
+            vec![vec![3, 0], vec![2, 5]],
+            block.grid(|c| c.to_digit(10)).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("30\n2x", 2, 2, "x")]
+    #[case("30\n255", 2, 1, "255")]
+    fn should_point_to_invalid_cell(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        let block = &blocks(input)[0];
+        let err = block.grid(|c| c.to_digit(10)).unwrap_err();
+
+        assert_eq!(
+            (line, column, text),
+            (err.line, err.column, err.text.as_str())
+        );
+    }
+}
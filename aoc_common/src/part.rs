@@ -0,0 +1,65 @@
+use serde::{de, Deserialize, Deserializer};
+use std::{fmt, str::FromStr};
+
+/// Each puzzle is made of two parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "a" => Ok(Part::A),
+            "B" | "b" => Ok(Part::B),
+            _ => Err(format!(
+                "Invalid puzzle option: \"{}\". Available values are: A | B.",
+                s
+            )),
+        }
+    }
+}
+
+/// From a string like in the command line, eg: in config file.
+impl<'de> Deserialize<'de> for Part {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "A"),
+            Part::B => write!(f, "B"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Part;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("A", Part::A)]
+    #[case("a", Part::A)]
+    #[case("B", Part::B)]
+    #[case("b", Part::B)]
+    fn should_parse_part(#[case] input: &str, #[case] expected: Part) {
+        assert_eq!(expected, input.parse::<Part>().unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("C")]
+    #[case("AB")]
+    fn should_not_parse_part(#[case] input: &str) {
+        assert!(input.parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// System allocator counting allocations and live memory, so that [`measure`] can report them.
+/// A binary opts in with:
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: aoc_common::CountingAllocator = aoc_common::CountingAllocator;
+/// ```
+/// Without it phases report no allocations.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: u64) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: u64) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size() as u64);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Counted as a new allocation replacing the old one.
+            Self::record_dealloc(layout.size() as u64);
+            Self::record_alloc(new_size as u64);
+        }
+        new_ptr
+    }
+}
+
+/// Wall time and memory used by a phase of a day: reading input, parsing or solving a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phase {
+    pub name: String,
+    pub elapsed: Duration,
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+    /// Sum of allocated bytes, freed ones included.
+    pub allocated_bytes: u64,
+    /// Highest memory in use during phase, on top of memory in use when it started.
+    pub peak_bytes: u64,
+}
+
+/// Run `f` as phase `name`. Memory counts are process wide: they are only meaningful
+/// when [`CountingAllocator`] is installed and nothing else runs meanwhile.
+pub fn measure<T>(name: impl Into<String>, f: impl FnOnce() -> T) -> (T, Phase) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let value = f();
+    let elapsed = start.elapsed();
+
+    let phase = Phase {
+        name: name.into(),
+        elapsed,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(current),
+    };
+
+    (value, phase)
+}
+
+/// Duration with a unit fitting its magnitude: "1.5 ms".
+pub fn format_duration(duration: Duration) -> String {
+    match duration.as_nanos() {
+        nanos @ 0..=999 => format!("{} ns", nanos),
+        nanos @ 1_000..=999_999 => format!("{:.1} µs", nanos as f64 / 1e3),
+        nanos @ 1_000_000..=999_999_999 => format!("{:.1} ms", nanos as f64 / 1e6),
+        nanos => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+/// Markdown table of phases.
+pub fn table(phases: &[Phase]) -> String {
+    let mut table = format!(
+        "| {:<8} | {:>10} | {:>11} | {:>10} | {:>10} |\n|:---------|-----------:|------------:|-----------:|-----------:|\n",
+        "phase", "time", "allocations", "allocated", "peak"
+    );
+
+    for phase in phases {
+        table.push_str(&format!(
+            "| {:<8} | {:>10} | {:>11} | {:>10} | {:>10} |\n",
+            phase.name,
+            format_duration(phase.elapsed),
+            phase.allocations,
+            format_bytes(phase.allocated_bytes),
+            format_bytes(phase.peak_bytes)
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_bytes, format_duration, measure, table, CountingAllocator};
+    use rstest::rstest;
+    use std::{hint::black_box, time::Duration};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn should_count_allocations_of_phase() {
+        let (len, phase) = measure("parse", || {
+            let buffers = (0..10).map(|_| vec![0u8; 4096]).collect::<Vec<_>>();
+            black_box(buffers).len()
+        });
+
+        assert_eq!(10, len);
+        assert_eq!("parse", phase.name);
+        // Other tests may allocate meanwhile: counts are lower bounds.
+        assert!(phase.allocations >= 11);
+        assert!(phase.allocated_bytes >= 40960);
+        assert!(phase.peak_bytes >= 40960);
+    }
+
+    #[test]
+    fn should_not_count_memory_in_use_before_phase() {
+        let before = black_box(vec![0u8; 1 << 24]);
+        let (_, phase) = measure("part A", || black_box(before.len() * 2));
+
+        assert!(phase.peak_bytes < 1 << 24);
+    }
+
+    #[rstest]
+    #[case(Duration::from_nanos(999), "999 ns")]
+    #[case(Duration::from_micros(1500), "1.5 ms")]
+    #[case(Duration::from_secs(2), "2.00 s")]
+    fn should_format_duration(#[case] duration: Duration, #[case] expected: &str) {
+        assert_eq!(expected, format_duration(duration));
+    }
+
+    #[rstest]
+    #[case(512, "512 B")]
+    #[case(2048, "2.0 KiB")]
+    #[case(3 * 1_048_576, "3.0 MiB")]
+    fn should_format_bytes(#[case] bytes: u64, #[case] expected: &str) {
+        assert_eq!(expected, format_bytes(bytes));
+    }
+
+    #[test]
+    fn should_render_a_row_per_phase() {
+        let (_, phase) = measure("read", || ());
+        let table = table(&[phase.clone(), phase]);
+
+        assert_eq!(4, table.lines().count());
+        assert!(table.lines().nth(2).unwrap().starts_with("| read "));
+    }
+}
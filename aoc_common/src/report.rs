@@ -0,0 +1,130 @@
+use std::{fmt::Write, str::FromStr, time::Duration};
+
+use crate::Input;
+
+/// How answers are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// A sentence for humans.
+    #[default]
+    Text,
+    /// One json object per answer, see [`Report::to_json`].
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!(
+                "Invalid format: \"{}\". Available values are: text | json.",
+                s
+            )),
+        }
+    }
+}
+
+/// An answer with what's needed to trace it back.
+pub struct Report<'a> {
+    pub day: u8,
+    /// Puzzle option, a [`crate::Part`] for most of the days.
+    pub part: String,
+    pub answer: String,
+    /// Time spent parsing input and solving.
+    pub elapsed: Duration,
+    pub input: &'a Input,
+}
+
+impl Report<'_> {
+    /// Eg: `{"day":1,"part":"A","answer":"68467","elapsed_ms":0.154,"input_path":"day_1/src/input.txt"}`.
+    /// `input_path` is null when input doesn't come from a file.
+    pub fn to_json(&self) -> String {
+        let input_path = match self.input {
+            Input::File(path) => json_string(&path.display().to_string()),
+            Input::Stdin | Input::Str(_) => String::from("null"),
+        };
+
+        format!(
+            "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ms\":{:.3},\"input_path\":{}}}",
+            self.day,
+            json_string(&self.part),
+            json_string(&self.answer),
+            self.elapsed.as_secs_f64() * 1000.0,
+            input_path
+        )
+    }
+}
+
+/// Quote and escape a string as a json string.
+pub fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_string, Format, Report};
+    use crate::Input;
+    use rstest::rstest;
+    use std::time::Duration;
+
+    #[rstest]
+    #[case("text", Format::Text)]
+    #[case("json", Format::Json)]
+    fn should_parse_format(#[case] input: &str, #[case] expected: Format) {
+        assert_eq!(expected, input.parse().unwrap());
+    }
+
+    #[rstest]
+    fn should_not_parse_format() {
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[rstest]
+    #[case("MQSHJMWNH", "\"MQSHJMWNH\"")]
+    #[case("a \"b\"\\c", "\"a \\\"b\\\"\\\\c\"")]
+    #[case("1\n2\u{1}", "\"1\\n2\\u0001\"")]
+    fn should_escape_json_string(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(expected, json_string(input));
+    }
+
+    #[rstest]
+    #[case(Input::File("day_1/src/input.txt".into()), "\"day_1/src/input.txt\"")]
+    #[case(Input::Stdin, "null")]
+    #[case(Input::Str(String::from("1000")), "null")]
+    fn should_format_report_as_json(#[case] input: Input, #[case] input_path: &str) {
+        let report = Report {
+            day: 1,
+            part: String::from("A"),
+            answer: String::from("68467"),
+            elapsed: Duration::from_micros(1500),
+            input: &input,
+        };
+
+        assert_eq!(
+            format!(
+                "{{\"day\":1,\"part\":\"A\",\"answer\":\"68467\",\"elapsed_ms\":1.500,\"input_path\":{}}}",
+                input_path
+            ),
+            report.to_json()
+        );
+    }
+}
@@ -0,0 +1,411 @@
+//! Searches over states of any type: [`bfs`], [`dijkstra`] and [`astar`] find a cheapest path to a goal,
+//! [`memoized_dfs`] computes the value of a state from the values of the states it leads to.
+//! States only need `Clone + Eq + Hash`, moves from a state are given by a closure. Eg: shortest path in a maze:
+//! ```
+//! use aoc_common::search::bfs;
+//!
+//! let maze = ["S.#", "#.#", "#.E"];
+//! let open = |(x, y): (i64, i64)| maze.get(y as usize).and_then(|row| row.as_bytes().get(x as usize)) != Some(&b'#');
+//! let search = bfs(
+//!     (0, 0),
+//!     |&(x, y)| [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+//!         .into_iter()
+//!         .filter(|&(x, y)| x >= 0 && y >= 0 && x < 3 && y < 3 && open((x, y))),
+//!     |&state| state == (2, 2),
+//! );
+//!
+//! assert_eq!(4, search.path().unwrap().cost);
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// States from start to goal, both included, with the cost of moving along them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub states: Vec<S>,
+    pub cost: u64,
+}
+
+impl<S> Path<S> {
+    /// Number of moves, one less than states.
+    pub fn steps(&self) -> usize {
+        self.states.len().saturating_sub(1)
+    }
+}
+
+/// How much of the state space a search went through.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose moves were explored (or whose value was computed, for [`memoized_dfs`]).
+    pub visited: usize,
+    /// Distinct states reached, visited or not.
+    pub discovered: usize,
+    /// Largest number of states waiting to be visited (or deepest recursion, for [`memoized_dfs`]).
+    pub max_frontier: usize,
+}
+
+/// States reached by a search, with the cheapest known way to each of them.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    /// Per state: state it was reached from and cost from start.
+    parents: Vec<Option<usize>>,
+    costs: Vec<u64>,
+    goal: Option<usize>,
+    pub stats: Stats,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(start: S) -> Self {
+        let mut search = Self {
+            states: Vec::new(),
+            index: HashMap::new(),
+            parents: Vec::new(),
+            costs: Vec::new(),
+            goal: None,
+            stats: Stats::default(),
+        };
+        search.discover(start, None, 0);
+        search
+    }
+
+    /// Record a newly reached state, returns its index.
+    fn discover(&mut self, state: S, parent: Option<usize>, cost: u64) -> usize {
+        let idx = self.states.len();
+
+        self.index.insert(state.clone(), idx);
+        self.states.push(state);
+        self.parents.push(parent);
+        self.costs.push(cost);
+        self.stats.discovered += 1;
+
+        idx
+    }
+
+    /// First goal state visited, `None` when no goal can be reached.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.map(|idx| &self.states[idx])
+    }
+
+    /// Cheapest path from start to goal.
+    pub fn path(&self) -> Option<Path<S>> {
+        self.goal.map(|idx| self.path_from(idx))
+    }
+
+    /// Cheapest known path from start to `state`, `None` when it wasn't reached.
+    /// It's the cheapest one for visited states, see [`Search::cost`].
+    pub fn path_to(&self, state: &S) -> Option<Path<S>> {
+        self.index.get(state).map(|idx| self.path_from(*idx))
+    }
+
+    /// Cost of the cheapest known path from start to `state`. It's the cheapest overall when `state` was visited,
+    /// or when it was reached by [`bfs`]. A search without goal visits every reachable state.
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.index.get(state).map(|idx| self.costs[*idx])
+    }
+
+    /// Every reached state with the cost of its cheapest known path, in order of discovery.
+    pub fn reached(&self) -> impl Iterator<Item = (&S, u64)> {
+        self.states.iter().zip(self.costs.iter().copied())
+    }
+
+    fn path_from(&self, idx: usize) -> Path<S> {
+        let mut states = vec![self.states[idx].clone()];
+        let mut current = idx;
+
+        while let Some(parent) = self.parents[current] {
+            states.push(self.states[parent].clone());
+            current = parent;
+        }
+        states.reverse();
+
+        Path {
+            states,
+            cost: self.costs[idx],
+        }
+    }
+}
+
+/// Breadth first search from `start` until a state matching `is_goal`, every move costs 1.
+/// `neighbors` gives states one move away.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(idx) = queue.pop_front() {
+        search.stats.visited += 1;
+
+        if is_goal(&search.states[idx]) {
+            search.goal = Some(idx);
+            break;
+        }
+
+        for next in neighbors(&search.states[idx]) {
+            if !search.index.contains_key(&next) {
+                let cost = search.costs[idx] + 1;
+                queue.push_back(search.discover(next, Some(idx), cost));
+            }
+        }
+        search.stats.max_frontier = search.stats.max_frontier.max(queue.len());
+    }
+
+    search
+}
+
+/// Cheapest path from `start` to a state matching `is_goal`, `neighbors` gives states one move away with the move cost.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbors, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], visiting first states that `heuristic` estimates closer to a goal.
+/// Path is the cheapest one as long as `heuristic` never overestimates the cost to a goal, eg: manhattan distance on a grid.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new(start);
+    // Estimated total cost first, then cost so far so that ties go deeper.
+    let mut heap = BinaryHeap::from([(Reverse(heuristic(&search.states[0])), 0, 0)]);
+
+    while let Some((_, cost, idx)) = heap.pop() {
+        // A cheaper way was found after this one was queued.
+        if cost > search.costs[idx] {
+            continue;
+        }
+        search.stats.visited += 1;
+
+        if is_goal(&search.states[idx]) {
+            search.goal = Some(idx);
+            break;
+        }
+
+        for (next, step) in neighbors(&search.states[idx]) {
+            let next_cost = cost + step;
+            let next_idx = match search.index.get(&next) {
+                Some(&next_idx) if search.costs[next_idx] <= next_cost => continue,
+                Some(&next_idx) => {
+                    search.parents[next_idx] = Some(idx);
+                    search.costs[next_idx] = next_cost;
+                    next_idx
+                }
+                None => search.discover(next, Some(idx), next_cost),
+            };
+            let estimate = next_cost + heuristic(&search.states[next_idx]);
+
+            heap.push((Reverse(estimate), next_cost, next_idx));
+        }
+        search.stats.max_frontier = search.stats.max_frontier.max(heap.len());
+    }
+
+    search
+}
+
+/// Value of `start`, where `value` computes the value of a state calling `visit` for the states it depends on.
+/// Each state is computed once, states can't depend on themselves. Eg: number of ways down a lattice:
+/// ```
+/// use aoc_common::search::memoized_dfs;
+///
+/// let (paths, stats) = memoized_dfs((0, 0), |&(x, y), visit| match (x, y) {
+///     (2, _) | (_, 2) => 1u64,
+///     _ => visit((x + 1, y)) + visit((x, y + 1)),
+/// });
+///
+/// assert_eq!(6, paths);
+/// assert_eq!(8, stats.visited);
+/// ```
+pub fn memoized_dfs<S, V>(start: S, value: impl Fn(&S, &mut dyn FnMut(S) -> V) -> V) -> (V, Stats)
+where
+    S: Clone + Eq + Hash,
+    V: Clone,
+{
+    let mut memo = HashMap::new();
+    let mut stats = Stats::default();
+    let start_value = visit(start, &value, &mut memo, &mut stats, 1);
+
+    (start_value, stats)
+}
+
+/// Computes the value of a state, see [`memoized_dfs`].
+type Value<'a, S, V> = dyn Fn(&S, &mut dyn FnMut(S) -> V) -> V + 'a;
+
+fn visit<S, V>(
+    state: S,
+    value: &Value<S, V>,
+    memo: &mut HashMap<S, V>,
+    stats: &mut Stats,
+    depth: usize,
+) -> V
+where
+    S: Clone + Eq + Hash,
+    V: Clone,
+{
+    if let Some(known) = memo.get(&state) {
+        return known.clone();
+    }
+
+    stats.visited += 1;
+    stats.discovered += 1;
+    stats.max_frontier = stats.max_frontier.max(depth);
+
+    let computed = value(&state, &mut |next| {
+        visit(next, value, memo, stats, depth + 1)
+    });
+    memo.insert(state, computed.clone());
+
+    computed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra, memoized_dfs, Path};
+    use rstest::rstest;
+
+    type Point = (i64, i64);
+
+    /// Open cells of `maze` next to `point`.
+    fn moves<'a>(maze: &'a [&str], (x, y): Point) -> impl Iterator<Item = Point> + 'a {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(move |&(x, y)| {
+                x >= 0
+                    && y >= 0
+                    && maze
+                        .get(y as usize)
+                        .and_then(|row| row.as_bytes().get(x as usize))
+                        .is_some_and(|cell| *cell != b'#')
+            })
+    }
+
+    /// Cost of entering a cell is its digit.
+    fn weighted<'a>(maze: &'a [&str], point: Point) -> impl Iterator<Item = (Point, u64)> + 'a {
+        moves(maze, point).map(|(x, y)| {
+            let cell = maze[y as usize].as_bytes()[x as usize];
+            ((x, y), (cell - b'0') as u64)
+        })
+    }
+
+    const MAZE: [&str; 4] = ["....#", ".##.#", "...#.", "#...."];
+
+    #[rstest]
+    #[case((4, 3), Some(7))]
+    #[case((3, 0), Some(3))]
+    #[case((0, 0), Some(0))]
+    #[case((4, 0), None)]
+    fn should_find_shortest_path(#[case] goal: Point, #[case] expected: Option<u64>) {
+        let search = bfs((0, 0), |p| moves(&MAZE, *p), |p| *p == goal);
+
+        assert_eq!(expected, search.path().map(|path| path.cost));
+        assert_eq!(expected.is_some(), search.goal().is_some());
+        if let Some(path) = search.path() {
+            assert_eq!(path.cost as usize, path.steps());
+            assert_eq!((0, 0), path.states[0]);
+            assert!(path
+                .states
+                .windows(2)
+                .all(|pair| moves(&MAZE, pair[0]).any(|next| next == pair[1])));
+        }
+    }
+
+    #[test]
+    fn should_reach_every_state_without_goal() {
+        let search = bfs((0, 0), |p| moves(&MAZE, *p), |_| false);
+
+        assert_eq!(None, search.path());
+        assert_eq!(14, search.stats.visited);
+        assert_eq!(14, search.stats.discovered);
+        assert_eq!(Some(7), search.cost(&(4, 3)));
+        assert_eq!(None, search.cost(&(4, 0)));
+        assert_eq!(
+            Some(vec![(0, 0), (1, 0), (2, 0), (3, 0), (3, 1)]),
+            search.path_to(&(3, 1)).map(|path| path.states)
+        );
+        assert_eq!(14, search.reached().count());
+    }
+
+    const WEIGHTS: [&str; 3] = ["1163751", "1381373", "2136511"];
+
+    #[test]
+    fn should_find_cheapest_path() {
+        let goal = (6, 2);
+        let by_dijkstra = dijkstra((0, 0), |p| weighted(&WEIGHTS, *p), |p| *p == goal);
+        let by_astar = astar(
+            (0, 0),
+            |p| weighted(&WEIGHTS, *p),
+            |&(x, y)| (goal.0 - x).unsigned_abs() + (goal.1 - y).unsigned_abs(),
+            |p| *p == goal,
+        );
+        let path = by_dijkstra.path().unwrap();
+
+        assert_eq!(20, path.cost);
+        assert_eq!(
+            path.cost,
+            path.states[1..]
+                .iter()
+                .map(|&(x, y)| (WEIGHTS[y as usize].as_bytes()[x as usize] - b'0') as u64)
+                .sum::<u64>()
+        );
+        assert_eq!(Some(path), by_astar.path());
+        assert!(by_astar.stats.visited <= by_dijkstra.stats.visited);
+    }
+
+    #[test]
+    fn should_update_cheaper_paths() {
+        // Direct edge to c is more expensive than going through b.
+        let edges = |state: &char| match state {
+            'a' => vec![('c', 10), ('b', 1)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra('a', edges, |state| *state == 'd');
+
+        assert_eq!(
+            Some(Path {
+                states: vec!['a', 'b', 'c', 'd'],
+                cost: 3
+            }),
+            search.path()
+        );
+        assert_eq!(4, search.stats.discovered);
+    }
+
+    #[test]
+    fn should_compute_each_state_once() {
+        let (paths, stats) = memoized_dfs((0u64, 0u64), |&(x, y), visit| match (x, y) {
+            (10, _) | (_, 10) => 1u64,
+            _ => visit((x + 1, y)) + visit((x, y + 1)),
+        });
+
+        // 20 moves, 10 of them right.
+        assert_eq!(184756, paths);
+        assert_eq!(120, stats.visited);
+        assert_eq!(20, stats.max_frontier);
+    }
+}
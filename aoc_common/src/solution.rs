@@ -0,0 +1,225 @@
+use std::io::BufRead;
+
+use crate::{measure, profile, Error, Param, Params, Part, Phase, PART_OPTION};
+
+/// A day puzzle: input is parsed once, then each part is solved from parsed input.
+pub trait Solution {
+    /// Day of the puzzle, eg: 5 for <https://adventofcode.com/2022/day/5>.
+    const DAY: u8;
+    /// Title of the puzzle.
+    const TITLE: &'static str;
+
+    /// Numbers the puzzle depends on, eg: day 7 disk size. They can be changed without recompiling, see [`crate::config`].
+    const PARAMS: &'static [Param] = &[];
+
+    /// Puzzle option the day binary takes, see [`crate::usage`].
+    const OPTION: &'static str = PART_OPTION;
+
+    /// Input shared by both parts.
+    type Input;
+
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error>;
+
+    fn part_a(input: &Self::Input, params: &Params) -> Result<String, Error>;
+
+    fn part_b(input: &Self::Input, params: &Params) -> Result<String, Error>;
+
+    /// Parse input and solve requested part with default params, see [`Solution::solve_with`].
+    fn solve(reader: &mut impl BufRead, part: Part) -> Result<String, Error> {
+        Self::solve_with(reader, part, &Params::defaults(Self::PARAMS))
+    }
+
+    /// Parse input and solve requested part, in tracing spans: `day > parse` and `day > part`.
+    fn solve_with(reader: &mut impl BufRead, part: Part, params: &Params) -> Result<String, Error> {
+        let _day = tracing::info_span!("day", day = Self::DAY).entered();
+        let input = tracing::info_span!("parse").in_scope(|| Self::parse(reader))?;
+        let _part = tracing::info_span!("part", %part).entered();
+
+        match part {
+            Part::A => Self::part_a(&input, params),
+            Part::B => Self::part_b(&input, params),
+        }
+    }
+}
+
+/// Type erased [`Solution`], so that days with different inputs can live in the same registry.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    pub title: &'static str,
+    pub params: &'static [Param],
+    /// Puzzle option of the day binary, see [`Solution::OPTION`].
+    pub option: &'static str,
+    pub solve: fn(&mut dyn BufRead, Part, &Params) -> Result<String, Error>,
+    /// Like `solve` for many parts, parsing once and measuring parse and each part as a [`Phase`].
+    pub profile: fn(&str, &[Part], &Params) -> Result<Profile, Error>,
+}
+
+/// Answers of a day with the phases spent on them.
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub answers: Vec<(Part, String)>,
+    pub phases: Vec<Phase>,
+}
+
+impl Profile {
+    /// Markdown table of phases.
+    pub fn table(&self) -> String {
+        profile::table(&self.phases)
+    }
+}
+
+impl Entry {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            title: S::TITLE,
+            params: S::PARAMS,
+            option: S::OPTION,
+            solve: solve::<S>,
+            profile: profile::<S>,
+        }
+    }
+
+    /// Default value of every param of the day.
+    pub fn default_params(&self) -> Params {
+        Params::defaults(self.params)
+    }
+}
+
+fn solve<S: Solution>(
+    mut reader: &mut dyn BufRead,
+    part: Part,
+    params: &Params,
+) -> Result<String, Error> {
+    S::solve_with(&mut reader, part, params)
+}
+
+fn profile<S: Solution>(content: &str, parts: &[Part], params: &Params) -> Result<Profile, Error> {
+    let (input, parse) = measure("parse", || S::parse(&mut content.as_bytes()));
+    let input = input?;
+    let mut profile = Profile {
+        answers: Vec::new(),
+        phases: vec![parse],
+    };
+
+    for part in parts {
+        let (answer, phase) = measure(format!("part {}", part), || match part {
+            Part::A => S::part_a(&input, params),
+            Part::B => S::part_b(&input, params),
+        });
+
+        profile.answers.push((*part, answer?));
+        profile.phases.push(phase);
+    }
+
+    Ok(profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Entry, Solution};
+    use crate::{Error, Param, Params, ParseError, Part};
+    use std::io::BufRead;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+        const PARAMS: &'static [Param] = &[Param {
+            name: "times",
+            default: 1,
+            min: 0,
+            about: "Part A sum is multiplied by it.",
+        }];
+
+        type Input = Vec<u32>;
+
+        fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+            reader
+                .lines()
+                .enumerate()
+                .map(|(idx, l)| {
+                    let line = l?;
+                    line.parse::<u32>()
+                        .map_err(|_| ParseError::whole_line("Not a number.", idx + 1, &line).into())
+                })
+                .collect()
+        }
+
+        fn part_a(input: &Self::Input, params: &Params) -> Result<String, Error> {
+            Ok((input.iter().sum::<u32>() as u64 * params.get("times")).to_string())
+        }
+
+        fn part_b(input: &Self::Input, _: &Params) -> Result<String, Error> {
+            input
+                .iter()
+                .max()
+                .map(|m| m.to_string())
+                .ok_or_else(|| Error::NoAnswer(String::from("Empty input.")))
+        }
+    }
+
+    #[test]
+    fn should_solve_each_part() {
+        assert_eq!("6", Sum::solve(&mut "1\n2\n3".as_bytes(), Part::A).unwrap());
+        assert_eq!("3", Sum::solve(&mut "1\n2\n3".as_bytes(), Part::B).unwrap());
+    }
+
+    #[test]
+    fn should_profile_each_phase() {
+        let profile = (Entry::new::<Sum>().profile)(
+            "1\n2\n3",
+            &[Part::B, Part::A],
+            &Params::defaults(Sum::PARAMS),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![(Part::B, String::from("3")), (Part::A, String::from("6"))],
+            profile.answers
+        );
+        assert_eq!(
+            vec!["parse", "part B", "part A"],
+            profile
+                .phases
+                .iter()
+                .map(|phase| phase.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert!(
+            (Entry::new::<Sum>().profile)("1\nx", &[Part::A], &Params::defaults(Sum::PARAMS))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_solve_through_entry() {
+        let entry = Entry::new::<Sum>();
+
+        let params = entry.default_params();
+
+        assert_eq!(1, entry.day);
+        assert_eq!(
+            "6",
+            (entry.solve)(&mut "1\n2\n3".as_bytes(), Part::A, &params).unwrap()
+        );
+        assert!((entry.solve)(&mut "".as_bytes(), Part::B, &params).is_err());
+        assert!(matches!(
+            (entry.solve)(&mut "1\nx".as_bytes(), Part::A, &params),
+            Err(Error::Parse(ParseError { line: 2, .. }))
+        ));
+    }
+
+    #[test]
+    fn should_solve_with_params() {
+        let mut params = Params::defaults(Sum::PARAMS);
+        params.set(Sum::PARAMS, "times", 10).unwrap();
+
+        assert_eq!(
+            "60",
+            Sum::solve_with(&mut "1\n2\n3".as_bytes(), Part::A, &params).unwrap()
+        );
+    }
+}
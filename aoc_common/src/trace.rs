@@ -0,0 +1,22 @@
+use std::io::IsTerminal;
+use tracing_subscriber::EnvFilter;
+
+/// Print solver steps on stderr when asked: `RUST_LOG` (eg: `day_5=trace`) wins over verbosity,
+/// else 1 (`-v`) shows debug events and 2 (`-vv`) trace ones.
+/// Nothing is installed otherwise, so events cost only a disabled callsite check.
+pub fn init_tracing(verbosity: u8) {
+    let filter = match (std::env::var_os("RUST_LOG"), verbosity) {
+        (Some(_), _) => EnvFilter::from_default_env(),
+        (None, 0) => return,
+        (None, 1) => EnvFilter::new("debug"),
+        (None, _) => EnvFilter::new("trace"),
+    };
+
+    // Already installed when called twice, first one wins.
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .without_time()
+        .try_init();
+}
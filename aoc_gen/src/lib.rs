@@ -0,0 +1,465 @@
+//! Synthetic inputs for every day of [Advent of code 2022](https://adventofcode.com/2022),
+//! to stress test solvers on inputs far larger than the real ones.
+//! Inputs are well formed and deterministic for a given seed.
+
+use std::fmt::Write;
+
+pub const DEFAULT_SEED: u64 = 2022;
+
+/// Marker length of day 6 part B, the one inputs are built around.
+pub const SIGNAL_MARKER_LENGTH: usize = 14;
+
+const LOWER_CASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const STACKS: usize = 9;
+
+/// Generate input of a day where `size` is the number of items of its format (see [`Generator`] methods).
+/// None if day has no generator.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let mut generator = Generator::new(seed);
+
+    let input = match day {
+        1 => generator.calories(size),
+        2 => generator.rounds(size),
+        3 => generator.rucksacks(size),
+        4 => generator.pairs(size),
+        5 => generator.stacks(size),
+        6 => {
+            let size = size.max(SIGNAL_MARKER_LENGTH);
+            generator
+                .signal(size, SIGNAL_MARKER_LENGTH, size)
+                .expect("Marker at the end of signal always fits.")
+        }
+        7 => generator.terminal(size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+/// Random inputs generator (xorshift, good enough to shuffle puzzle inputs).
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        // Splitmix, so that close seeds don't give close states, and state is never 0.
+        let mut state = seed.wrapping_add(0x9e3779b97f4a7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d049bb133111eb);
+
+        Self {
+            state: (state ^ (state >> 31)).max(1),
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Random number in `low..=high`.
+    fn range(&mut self, low: usize, high: usize) -> usize {
+        low + (self.next() % (high - low + 1) as u64) as usize
+    }
+
+    fn pick(&mut self, chars: &[u8]) -> char {
+        chars[self.range(0, chars.len() - 1)] as char
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i));
+        }
+    }
+
+    /// Day 1: `size` elves carrying 1 to 5 items each.
+    pub fn calories(&mut self, size: usize) -> String {
+        let mut input = String::new();
+
+        for elf in 0..size {
+            if elf > 0 {
+                input.push('\n');
+            }
+            for _ in 0..self.range(1, 5) {
+                writeln!(input, "{}", self.range(1000, 60000)).unwrap();
+            }
+        }
+
+        input
+    }
+
+    /// Day 2: `size` rounds.
+    pub fn rounds(&mut self, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            writeln!(input, "{} {}", self.pick(b"ABC"), self.pick(b"XYZ")).unwrap();
+        }
+
+        input
+    }
+
+    /// Day 3: `size` rucksacks, rounded up to a multiple of 3 since they come in groups.
+    /// Compartments of a rucksack share exactly one item, rucksacks of a group share exactly one badge.
+    pub fn rucksacks(&mut self, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size.div_ceil(3) {
+            let mut letters = LETTERS.to_vec();
+            self.shuffle(&mut letters);
+
+            // Letters other than badge are split in a pool per rucksack, so only the badge is in all of them.
+            let (badge, pools) = (letters[0] as char, &letters[1..]);
+
+            for pool in pools.chunks(pools.len() / 3) {
+                // Each compartment takes items from its own half of the pool, plus the shared one.
+                let (shared, first_items, second_items) =
+                    (pool[0] as char, &pool[1..9], &pool[9..17]);
+                let half = self.range(2, 16);
+
+                let mut first = vec![shared, badge];
+                first.extend((2..half).map(|_| self.pick(first_items)));
+                let mut second = vec![shared];
+                second.extend((1..half).map(|_| self.pick(second_items)));
+
+                self.shuffle(&mut first);
+                self.shuffle(&mut second);
+
+                writeln!(
+                    input,
+                    "{}{}",
+                    first.into_iter().collect::<String>(),
+                    second.into_iter().collect::<String>()
+                )
+                .unwrap();
+            }
+        }
+
+        input
+    }
+
+    /// Day 4: `size` pairs of ranges.
+    pub fn pairs(&mut self, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let (a, c) = (self.range(1, 98), self.range(1, 98));
+            let (b, d) = (self.range(a, 99), self.range(c, 99));
+            writeln!(input, "{}-{},{}-{}", a, b, c, d).unwrap();
+        }
+
+        input
+    }
+
+    /// Day 5: drawing of 9 stacks followed by `size` moves.
+    /// Moves are consistent: they never take more crates than a stack holds.
+    pub fn stacks(&mut self, size: usize) -> String {
+        let mut heights = (0..STACKS)
+            .map(|_| self.range(1, 8))
+            .collect::<Vec<usize>>();
+        let max_height = *heights.iter().max().unwrap();
+        let mut input = String::new();
+
+        for row in (0..max_height).rev() {
+            let line = heights
+                .iter()
+                .map(|height| match row < *height {
+                    true => format!("[{}]", self.pick(&LETTERS[26..])),
+                    false => String::from("   "),
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            writeln!(input, "{}", line).unwrap();
+        }
+
+        let numbers = (1..=STACKS)
+            .map(|n| format!(" {} ", n))
+            .collect::<Vec<String>>()
+            .join(" ");
+        writeln!(input, "{}\n", numbers).unwrap();
+
+        for _ in 0..size {
+            let not_empty = (0..STACKS)
+                .filter(|stack| heights[*stack] > 0)
+                .collect::<Vec<usize>>();
+            let from = not_empty[self.range(0, not_empty.len() - 1)];
+            let to = (from + self.range(1, STACKS - 1)) % STACKS;
+            let n = self.range(1, heights[from].min(8));
+
+            heights[from] -= n;
+            heights[to] += n;
+            writeln!(input, "move {} from {} to {}", n, from + 1, to + 1).unwrap();
+        }
+
+        input
+    }
+
+    /// Day 6: signal of `size` chars where the first sequence of `marker_length` unique chars ends at `position`,
+    /// which is what day 6 `find_marker` returns.
+    pub fn signal(
+        &mut self,
+        size: usize,
+        marker_length: usize,
+        position: usize,
+    ) -> Result<String, String> {
+        if marker_length == 0 || marker_length > LOWER_CASE.len() {
+            return Err(format!(
+                "Marker length has to be between 1 and {}.",
+                LOWER_CASE.len()
+            ));
+        }
+        if position < marker_length || position > size {
+            return Err(format!(
+                "Marker position has to be between {} and {}.",
+                marker_length, size
+            ));
+        }
+        if marker_length == 1 && position > 1 {
+            return Err(String::from(
+                "A marker of length 1 is always at position 1.",
+            ));
+        }
+
+        let mut letters = LOWER_CASE.to_vec();
+        self.shuffle(&mut letters);
+
+        let marker = &letters[..marker_length];
+        // Chars before marker come from one letter less than marker length, so they can't make a marker.
+        // First char of marker is one of them, and it's repeated right before the marker so that windows
+        // overlapping the marker always contain it twice.
+        let before = &letters[..marker_length - 1];
+        let before_length = position - marker_length;
+
+        let mut input = (0..before_length)
+            .map(|i| match i + 2 >= before_length {
+                true => marker[0] as char,
+                false => self.pick(before),
+            })
+            .collect::<String>();
+
+        input.extend(marker.iter().map(|c| *c as char));
+        input.extend((position..size).map(|_| self.pick(LOWER_CASE)));
+        input.push('\n');
+
+        Ok(input)
+    }
+
+    /// Day 7: terminal session exploring a random directory tree with about `size` files.
+    pub fn terminal(&mut self, size: usize) -> String {
+        let mut input = String::from("$ cd /\n");
+        let mut files = 0;
+
+        self.explore(&mut input, &mut files, size, 0);
+        input
+    }
+
+    fn explore(&mut self, input: &mut String, files: &mut usize, size: usize, depth: usize) {
+        let file_count = self.range(1, 8);
+        let dir_count = match depth < 8 {
+            true => self.range(2, 6),
+            false => 0,
+        };
+        let dirs = self.dir_names(dir_count);
+
+        input.push_str("$ ls\n");
+        for dir in &dirs {
+            writeln!(input, "dir {}", dir).unwrap();
+        }
+        for _ in 0..file_count {
+            let name = self.name(1, 8);
+            let extension = [".txt", ".dat", ".log", ""][self.range(0, 3)];
+
+            writeln!(input, "{} {}{}", self.range(1000, 300000), name, extension).unwrap();
+        }
+        *files += file_count;
+
+        for dir in &dirs {
+            if *files >= size {
+                break;
+            }
+            writeln!(input, "$ cd {}", dir).unwrap();
+            self.explore(input, files, size, depth + 1);
+            input.push_str("$ cd ..\n");
+        }
+    }
+
+    /// Random lower case name of `low..=high` letters.
+    fn name(&mut self, low: usize, high: usize) -> String {
+        (0..self.range(low, high))
+            .map(|_| self.pick(LOWER_CASE))
+            .collect()
+    }
+
+    /// `count` different names of dirs in the same dir, some are prefix of the next one as in real inputs: "a" and "ab".
+    fn dir_names(&mut self, count: usize) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+
+        while names.len() < count {
+            let name = match names.last() {
+                Some(last) if self.range(0, 1) == 0 => format!("{}{}", last, self.pick(LOWER_CASE)),
+                _ => self.name(1, 3),
+            };
+
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, Generator, DEFAULT_SEED, STACKS};
+    use aoc_common::{Entry, Part};
+    use rstest::rstest;
+    use std::collections::HashSet;
+
+    #[rstest]
+    #[case(Entry::new::<day_1::Puzzle>())]
+    #[case(Entry::new::<day_2::Puzzle>())]
+    #[case(Entry::new::<day_3::Puzzle>())]
+    #[case(Entry::new::<day_4::Puzzle>())]
+    #[case(Entry::new::<day_5::Puzzle>())]
+    #[case(Entry::new::<day_6::Puzzle>())]
+    #[case(Entry::new::<day_7::Puzzle>())]
+    fn should_generate_solvable_input(#[case] entry: Entry) {
+        for (size, seed) in [(1, DEFAULT_SEED), (100, DEFAULT_SEED), (1000, 42)] {
+            let input = generate(entry.day, size, seed).unwrap();
+
+            for part in [Part::A, Part::B] {
+                if let Err(err) =
+                    (entry.solve)(&mut input.as_bytes(), part, &entry.default_params())
+                {
+                    panic!("Day {} part {}: {}", entry.day, part, err.render());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_be_deterministic() {
+        assert_eq!(generate(3, 100, 1), generate(3, 100, 1));
+        assert_ne!(generate(3, 100, 1), generate(3, 100, 2));
+        assert_eq!(None, generate(26, 100, 1));
+    }
+
+    fn items(content: &str) -> HashSet<char> {
+        content.chars().collect()
+    }
+
+    #[test]
+    fn should_share_exactly_one_item() {
+        let input = generate(3, 300, DEFAULT_SEED).unwrap();
+        let rucksacks = input.lines().collect::<Vec<&str>>();
+
+        for rucksack in &rucksacks {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+
+            assert_eq!(1, items(first).intersection(&items(second)).count());
+        }
+
+        for group in rucksacks.chunks(3) {
+            let badges = items(group[0])
+                .into_iter()
+                .filter(|c| group[1].contains(*c) && group[2].contains(*c))
+                .count();
+
+            assert_eq!(1, badges);
+        }
+    }
+
+    #[test]
+    fn should_generate_consistent_moves() {
+        let input = generate(5, 1000, DEFAULT_SEED).unwrap();
+        let (drawing, moves) = input.split_once("\n\n").unwrap();
+        let mut heights = [0usize; STACKS];
+
+        for line in drawing.lines() {
+            for (stack, c) in line.chars().skip(1).step_by(4).enumerate() {
+                if c.is_ascii_uppercase() {
+                    heights[stack] += 1;
+                }
+            }
+        }
+
+        for line in moves.lines() {
+            let numbers = line
+                .split(' ')
+                .filter_map(|word| word.parse::<usize>().ok())
+                .collect::<Vec<usize>>();
+            let (n, from, to) = (numbers[0], numbers[1] - 1, numbers[2] - 1);
+
+            assert!(heights[from] >= n, "Inconsistent move: {}", line);
+            assert_ne!(from, to);
+            heights[from] -= n;
+            heights[to] += n;
+        }
+    }
+
+    #[test]
+    fn should_name_dirs_after_their_siblings() {
+        let input = generate(7, 1000, DEFAULT_SEED).unwrap();
+        let prefixed = input.split("$ ls\n").any(|listing| {
+            let dirs = listing
+                .lines()
+                .filter_map(|line| line.strip_prefix("dir "))
+                .collect::<Vec<&str>>();
+
+            dirs.iter()
+                .any(|a| dirs.iter().any(|b| a != b && b.starts_with(a)))
+        });
+
+        assert!(prefixed, "No dir name is prefix of a sibling one.");
+    }
+
+    #[rstest]
+    #[case(100, 14, 100)]
+    #[case(100, 14, 14)]
+    #[case(100, 14, 15)]
+    #[case(100, 14, 16)]
+    #[case(100, 14, 57)]
+    #[case(100, 4, 4)]
+    #[case(100, 4, 80)]
+    #[case(10, 2, 10)]
+    #[case(10, 1, 1)]
+    #[case(26, 26, 26)]
+    fn should_put_marker_at_position(
+        #[case] size: usize,
+        #[case] marker_length: usize,
+        #[case] position: usize,
+    ) {
+        for seed in 0..20 {
+            let signal = Generator::new(seed)
+                .signal(size, marker_length, position)
+                .unwrap();
+
+            assert_eq!(size, signal.trim_end().len());
+            assert_eq!(
+                Some(position),
+                day_6::find_marker(signal.trim_end(), marker_length)
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(100, 0, 10)]
+    #[case(100, 27, 30)]
+    #[case(100, 14, 13)]
+    #[case(100, 14, 101)]
+    #[case(100, 1, 2)]
+    fn should_not_put_marker_at_position(
+        #[case] size: usize,
+        #[case] marker_length: usize,
+        #[case] position: usize,
+    ) {
+        assert!(Generator::new(DEFAULT_SEED)
+            .signal(size, marker_length, position)
+            .is_err());
+    }
+}
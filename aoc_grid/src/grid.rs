@@ -0,0 +1,321 @@
+use aoc_common::{parse::Block, ParseError};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::Point;
+
+/// Rectangular grid of cells, eg: tree heights. Top left cell is at [`Point::ORIGIN`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row after row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid with every cell set to `cell`.
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Grid of `rows`, `None` when they don't have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Grid where each char of input lines is a cell converted by `cell`, eg: "30373\n25512" with digits as cells.
+    /// Errors point to the unexpected char or the row of another width.
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::from_block(
+            &Block {
+                line_number: 1,
+                lines: input.lines().collect(),
+            },
+            cell,
+        )
+    }
+
+    /// Like [`Grid::parse`] for a block of input, when the grid is followed by something else.
+    pub fn from_block(block: &Block, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        Ok(Self::from_rows(block.grid(cell)?).expect("Rows are checked to have the same width."))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point` is a cell of the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every point of the grid, row after row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its point, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // A grid without columns still has rows.
+        self.cells
+            .chunks(self.width.max(1))
+            .chain(std::iter::repeat(&[][..]))
+            .take(self.height)
+    }
+
+    /// Orthogonal neighbors of `point` inside the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors4()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// Neighbors of `point` inside the grid, diagonals included.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors8()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// Grid of cells converted by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Point of the first cell, row after row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Grid turned a quarter clockwise: first column becomes first row, read bottom up.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rotated(|grid, x, y| Point::new(y, (grid.height - 1 - x as usize) as i64))
+    }
+
+    /// Grid turned a quarter counterclockwise: last column becomes first row.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rotated(|grid, x, y| Point::new((grid.width - 1 - y as usize) as i64, x))
+    }
+
+    /// Rotated grid, where cell `(x, y)` is the cell of `self` at `source(self, x, y)`.
+    fn rotated(&self, source: impl Fn(&Self, i64, i64) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let (width, height) = (self.height, self.width);
+        let cells = (0..height as i64)
+            .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(self, x, y)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Panics when `point` is out of the grid, see [`Grid::get`].
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is out of the grid.", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is out of the grid.", point))
+    }
+}
+
+/// A line per row, cells are written one after the other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::Point;
+    use aoc_common::parse::blocks;
+    use rstest::rstest;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn should_parse_grid() {
+        let grid = digits("303\n255\n");
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&5), grid.get(Point::new(2, 1)));
+        assert_eq!(None, grid.get(Point::new(3, 1)));
+        assert_eq!(None, grid.get(Point::new(0, -1)));
+        assert_eq!(Some(Point::new(1, 1)), grid.position(|cell| *cell == 5));
+        assert_eq!("303\n255", grid.to_string());
+    }
+
+    #[rstest]
+    #[case("303\n2x5", "<input>:2:2: Unexpected cell.")]
+    #[case("303\n25", "<input>:2:1: Expected a row of 3 cells.")]
+    fn should_point_to_invalid_cells(#[case] input: &str, #[case] expected: &str) {
+        let err = Grid::parse(input, |c| c.to_digit(10)).unwrap_err();
+
+        assert_eq!(expected, err.to_string());
+    }
+
+    #[test]
+    fn should_parse_block() {
+        let input = "10 moves\n\n#.\n.#\n";
+        let grid = Grid::from_block(&blocks(input)[1], |c| Some(c == '#')).unwrap();
+
+        assert!(grid[Point::new(1, 1)]);
+        assert_eq!(
+            "<input>:3:2: Unexpected cell.",
+            Grid::from_block(&blocks(input)[1], |c| (c == '#').then_some(()))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), vec![(1, 0), (0, 1)], 3)]
+    #[case(Point::new(1, 1), vec![(1, 0), (2, 1), (1, 2), (0, 1)], 8)]
+    #[case(Point::new(2, 2), vec![(2, 1), (1, 2)], 3)]
+    fn should_list_neighbors_in_grid(
+        #[case] point: Point,
+        #[case] expected: Vec<(i64, i64)>,
+        #[case] adjacent: usize,
+    ) {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(
+            expected
+                .into_iter()
+                .map(|(x, y)| Point::new(x, y))
+                .collect::<Vec<_>>(),
+            grid.neighbors4(point).collect::<Vec<_>>()
+        );
+        assert_eq!(adjacent, grid.neighbors8(point).count());
+    }
+
+    #[test]
+    fn should_rotate() {
+        let grid = digits("123\n456");
+
+        assert_eq!("41\n52\n63", grid.rotate_right().to_string());
+        assert_eq!("36\n25\n14", grid.rotate_left().to_string());
+        assert_eq!(grid, grid.rotate_right().rotate_left());
+        assert_eq!(
+            grid,
+            grid.rotate_right()
+                .rotate_right()
+                .rotate_right()
+                .rotate_right()
+        );
+    }
+
+    #[test]
+    fn should_update_cells() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid[Point::new(1, 0)] = '#';
+        *grid.get_mut(Point::new(0, 1)).unwrap() = '#';
+
+        assert_eq!(".#\n#.", grid.to_string());
+        assert_eq!(".#\n#.", grid.map(|c| *c).to_string());
+        assert_eq!(
+            vec![Point::new(1, 0), Point::new(0, 1)],
+            grid.iter()
+                .filter(|(_, c)| **c == '#')
+                .map(|(point, _)| point)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_reject_uneven_rows() {
+        assert_eq!(None, Grid::from_rows(vec![vec![1, 2], vec![3]]));
+        assert_eq!(0, Grid::from_rows(Vec::<Vec<u8>>::new()).unwrap().height());
+    }
+
+    #[test]
+    #[should_panic(expected = "(2, 0) is out of the grid.")]
+    fn should_panic_out_of_grid() {
+        let _ = Grid::new(2, 2, 0)[Point::new(2, 0)];
+    }
+}
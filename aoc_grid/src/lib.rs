@@ -0,0 +1,12 @@
+//! 2D geometry shared by grid puzzles: [`Point`]s moved by [`Vector`]s, dense [`Grid`]s parsed from input
+//! and [`SparseGrid`]s for unbounded ones (eg: falling sand, beacons).
+//!
+//! `x` grows to the right and `y` grows downward, like rows of input: [`Vector::UP`] is `(0, -1)`.
+
+mod grid;
+mod point;
+mod sparse;
+
+pub use grid::Grid;
+pub use point::{Point, Vector};
+pub use sparse::SparseGrid;
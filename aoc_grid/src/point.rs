@@ -0,0 +1,233 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position, `y` grows downward.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A move between two points, eg: `Point { x: 1, y: 1 } - Point::ORIGIN`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Number of orthogonal steps to `other`.
+    pub fn manhattan(self, other: Self) -> u64 {
+        (other - self).manhattan()
+    }
+
+    /// Points one orthogonal step away, in [`Vector::ORTHOGONAL`] order.
+    pub fn neighbors4(self) -> [Self; 4] {
+        Vector::ORTHOGONAL.map(|vector| self + vector)
+    }
+
+    /// Points one step away, diagonals included, in [`Vector::ADJACENT`] order.
+    pub fn neighbors8(self) -> [Self; 8] {
+        Vector::ADJACENT.map(|vector| self + vector)
+    }
+}
+
+impl Vector {
+    pub const UP: Self = Self::new(0, -1);
+    pub const RIGHT: Self = Self::new(1, 0);
+    pub const DOWN: Self = Self::new(0, 1);
+    pub const LEFT: Self = Self::new(-1, 0);
+    /// Clockwise from up.
+    pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+    /// Clockwise from up, diagonals included.
+    pub const ADJACENT: [Self; 8] = [
+        Self::UP,
+        Self::new(1, -1),
+        Self::RIGHT,
+        Self::new(1, 1),
+        Self::DOWN,
+        Self::new(-1, 1),
+        Self::LEFT,
+        Self::new(-1, -1),
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Number of orthogonal steps it moves by.
+    pub fn manhattan(self) -> u64 {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Number of steps it moves by when diagonal steps are allowed.
+    pub fn chebyshev(self) -> u64 {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    /// A step of at most 1 on each axis toward the same direction, eg: a rope knot following the previous one.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Quarter turn clockwise, eg: up to right.
+    pub fn turn_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Quarter turn counterclockwise, eg: up to left.
+    pub fn turn_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, times: i64) -> Vector {
+        Vector::new(self.x * times, self.y * times)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:+}, {:+})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Point, Vector};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Point::new(0, 0), Point::new(0, 0), 0)]
+    #[case(Point::new(8, 7), Point::new(2, 10), 9)]
+    #[case(Point::new(-2, 15), Point::new(3, -5), 25)]
+    fn should_measure_manhattan_distance(
+        #[case] a: Point,
+        #[case] b: Point,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(expected, a.manhattan(b));
+        assert_eq!(expected, b.manhattan(a));
+    }
+
+    #[test]
+    fn should_move_points() {
+        let mut point = Point::new(1, 2);
+        point += Vector::RIGHT * 3;
+        point -= Vector::UP;
+
+        assert_eq!(Point::new(4, 3), point);
+        assert_eq!(Vector::new(4, 3), point - Point::ORIGIN);
+        assert_eq!(Vector::new(3, 1), Vector::new(4, 3) - Vector::new(1, 2));
+    }
+
+    #[rstest]
+    #[case(Vector::new(2, 0), Vector::new(1, 0), 2)]
+    #[case(Vector::new(2, -1), Vector::new(1, -1), 2)]
+    #[case(Vector::new(-1, -1), Vector::new(-1, -1), 1)]
+    #[case(Vector::new(0, 0), Vector::new(0, 0), 0)]
+    fn should_step_toward(#[case] vector: Vector, #[case] step: Vector, #[case] chebyshev: u64) {
+        assert_eq!(step, vector.signum());
+        assert_eq!(chebyshev, vector.chebyshev());
+    }
+
+    #[test]
+    fn should_turn() {
+        for (idx, vector) in Vector::ORTHOGONAL.iter().enumerate() {
+            assert_eq!(Vector::ORTHOGONAL[(idx + 1) % 4], vector.turn_right());
+            assert_eq!(Vector::ORTHOGONAL[(idx + 3) % 4], vector.turn_left());
+        }
+    }
+
+    #[test]
+    fn should_list_neighbors() {
+        let point = Point::new(1, 1);
+
+        assert_eq!(
+            [(1, 0), (2, 1), (1, 2), (0, 1)].map(|(x, y)| Point::new(x, y)),
+            point.neighbors4()
+        );
+        assert!(point
+            .neighbors8()
+            .iter()
+            .all(|neighbor| (*neighbor - point).chebyshev() == 1));
+        assert_eq!("(1, 1) (+0, -1)", format!("{} {}", point, Vector::UP));
+    }
+}
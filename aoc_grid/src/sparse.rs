@@ -0,0 +1,141 @@
+use std::{collections::HashMap, fmt};
+
+use crate::Point;
+
+/// Unbounded grid where only some points have a cell, eg: rocks and sand falling on them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the cell at `point`, returns the previous one.
+    pub fn insert(&mut self, point: Point, cell: T) -> Option<T> {
+        self.cells.insert(point, cell)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Number of points having a cell.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Cells with their point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    /// Top left and bottom right corners of the smallest rectangle holding every cell, `None` when empty.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(min, max), point| {
+            (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y)),
+            )
+        }))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(cells: I) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+/// Cells within [`SparseGrid::bounds`], a line per row, points without cell are `.`.
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            if y > min.y {
+                writeln!(f)?;
+            }
+            for x in min.x..=max.x {
+                match self.get(Point::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseGrid;
+    use crate::{Point, Vector};
+
+    #[test]
+    fn should_grow_without_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+        assert_eq!("", grid.to_string());
+
+        grid.insert(Point::new(498, 4), '#');
+        grid.insert(Point::new(500, 6), 'o');
+        grid.insert(Point::new(500, 4) + Vector::LEFT, '#');
+
+        assert_eq!(3, grid.len());
+        assert_eq!(
+            Some((Point::new(498, 4), Point::new(500, 6))),
+            grid.bounds()
+        );
+        assert_eq!("##.\n...\n..o", grid.to_string());
+
+        assert_eq!(Some('o'), grid.remove(Point::new(500, 6)));
+        assert!(!grid.contains(Point::new(500, 6)));
+        assert_eq!("##", grid.to_string());
+    }
+
+    #[test]
+    fn should_collect_cells() {
+        let grid = (-2..=2)
+            .map(|x| (Point::new(x, x.abs()), x))
+            .collect::<SparseGrid<i64>>();
+
+        assert_eq!(Some(&-1), grid.get(Point::new(-1, 1)));
+        assert_eq!(Some((Point::new(-2, 0), Point::new(2, 2))), grid.bounds());
+        assert_eq!(4, grid.iter().filter(|(point, _)| point.y > 0).count());
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Inputs stored on disk as: `<dir>/<year>/day_<day>.txt`.
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Cache in `AOC_CACHE_DIR`, else `$XDG_CACHE_HOME/aoc`, else `$HOME/.cache/aoc`.
+    /// None when none of them is set.
+    pub fn from_env() -> Option<Self> {
+        env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("XDG_CACHE_HOME").map(|dir| PathBuf::from(dir).join("aoc")))
+            .or_else(|| env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".cache/aoc")))
+            .map(Self::new)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Where input of a day is stored, whether it is cached or not.
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day_{}.txt", day))
+    }
+
+    /// Cached input, None when it is missing.
+    pub fn get(&self, year: u16, day: u8) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.path(year, day)) {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Store input, replacing the cached one. Returns where it is stored.
+    pub fn store(&self, year: u16, day: u8, input: &str) -> io::Result<PathBuf> {
+        let path = self.path(year, day);
+
+        fs::create_dir_all(self.dir.join(year.to_string()))?;
+        // Written aside then renamed, so an interrupted write never leaves a truncated input in cache.
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, input)?;
+        fs::rename(&partial, &path)?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::InputCache;
+    use std::{fs, path::PathBuf};
+
+    /// Empty cache in a temp dir unique to `name`.
+    pub fn temp_cache(name: &str) -> InputCache {
+        let dir = std::env::temp_dir().join(format!("aoc_input_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        InputCache::new(dir)
+    }
+
+    #[test]
+    fn should_key_inputs_by_year_and_day() {
+        let cache = InputCache::new("cache");
+
+        assert_eq!(PathBuf::from("cache/2022/day_5.txt"), cache.path(2022, 5));
+        assert_eq!(PathBuf::from("cache/2015/day_25.txt"), cache.path(2015, 25));
+    }
+
+    #[test]
+    fn should_store_and_get_input() {
+        let cache = temp_cache("cache");
+
+        assert_eq!(None, cache.get(2022, 1).unwrap());
+        assert_eq!(cache.path(2022, 1), cache.store(2022, 1, "1000\n").unwrap());
+        assert_eq!(Some(String::from("1000\n")), cache.get(2022, 1).unwrap());
+        assert_eq!(None, cache.get(2021, 1).unwrap());
+
+        cache.store(2022, 1, "2000\n").unwrap();
+        assert_eq!(Some(String::from("2000\n")), cache.get(2022, 1).unwrap());
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
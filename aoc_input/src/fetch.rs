@@ -0,0 +1,146 @@
+use std::{
+    env, fmt,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Gets a puzzle input from somewhere other than the cache.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchError {
+    /// No session token to authenticate with.
+    MissingSession,
+    /// Session token is rejected or expired.
+    Unauthorized,
+    /// Puzzle doesn't exist or is not unlocked yet.
+    NotFound { year: u16, day: u8 },
+    /// Too many requests, retry after given time when server tells it.
+    RateLimited(Option<Duration>),
+    /// Any other unexpected status.
+    Status(u16, String),
+    /// Server can't be reached.
+    Transport(String),
+    /// Cache can't be read or written.
+    Cache(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => write!(
+                f,
+                "Missing session token, copy the session cookie of the puzzle site in: AOC_SESSION."
+            ),
+            FetchError::Unauthorized => write!(f, "Session token is rejected, it may be expired."),
+            FetchError::NotFound { year, day } => {
+                write!(f, "Puzzle {} day {} is not available yet.", year, day)
+            }
+            FetchError::RateLimited(Some(retry_after)) => write!(
+                f,
+                "Too many requests, retry in: {}s.",
+                retry_after.as_secs()
+            ),
+            FetchError::RateLimited(None) => write!(f, "Too many requests, retry later."),
+            FetchError::Status(status, body) => {
+                write!(f, "Unexpected status: {}. {}", status, body.trim())
+            }
+            FetchError::Transport(err) => write!(f, "Unable to reach puzzle site: {}.", err),
+            FetchError::Cache(err) => write!(f, "Unable to use input cache: {}.", err),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Fetch inputs from the puzzle site, authenticated by the session cookie of a logged in browser.
+/// Requests are spaced by at least `min_interval`, to be gentle with the site.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    min_interval: Duration,
+    agent: ureq::Agent,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl HttpFetcher {
+    pub const DEFAULT_URL: &'static str = "https://adventofcode.com";
+    pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            min_interval: Self::DEFAULT_MIN_INTERVAL,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent("aoc_input (rust puzzle runner)")
+                .build(),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Fetcher authenticated by `AOC_SESSION`, reaching `AOC_URL` when set (eg: a [`crate::MockServer`]).
+    pub fn from_env() -> Result<Self, FetchError> {
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(FetchError::MissingSession)?;
+        let base_url = env::var("AOC_URL").unwrap_or_else(|_| Self::DEFAULT_URL.to_string());
+
+        Ok(Self::new(base_url, session.trim()))
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Wait until `min_interval` is elapsed since previous request.
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+
+        if let Some(wait) =
+            last_request.and_then(|last| self.min_interval.checked_sub(last.elapsed()))
+        {
+            thread::sleep(wait);
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.throttle();
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| FetchError::Transport(err.to_string())),
+            // Site answers 400 to anonymous or expired sessions.
+            Err(ureq::Error::Status(400 | 401 | 403, _)) => Err(FetchError::Unauthorized),
+            Err(ureq::Error::Status(404, _)) => Err(FetchError::NotFound { year, day }),
+            Err(ureq::Error::Status(429, response)) => Err(FetchError::RateLimited(
+                response
+                    .header("Retry-After")
+                    .and_then(|seconds| seconds.parse().ok())
+                    .map(Duration::from_secs),
+            )),
+            Err(ureq::Error::Status(status, response)) => Err(FetchError::Status(
+                status,
+                response.into_string().unwrap_or_default(),
+            )),
+            Err(ureq::Error::Transport(err)) => Err(FetchError::Transport(err.to_string())),
+        }
+    }
+}
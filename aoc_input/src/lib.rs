@@ -0,0 +1,14 @@
+//! Puzzle inputs of [Advent of code](https://adventofcode.com): a local cache keyed by year and day,
+//! filled on demand by a [`Fetcher`], plus a [`MockServer`] standing in for the puzzle site in tests.
+
+mod cache;
+mod fetch;
+mod manager;
+mod mock;
+
+pub use cache::InputCache;
+pub use fetch::{FetchError, Fetcher, HttpFetcher};
+pub use manager::{InputManager, Source};
+pub use mock::MockServer;
+
+pub const DEFAULT_YEAR: u16 = 2022;
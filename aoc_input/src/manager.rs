@@ -0,0 +1,163 @@
+use crate::{FetchError, Fetcher, InputCache};
+
+/// Where an input comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Cache,
+    Fetched,
+}
+
+/// Serves inputs from cache, fetching only the missing ones.
+pub struct InputManager<F> {
+    cache: InputCache,
+    fetcher: F,
+}
+
+impl<F: Fetcher> InputManager<F> {
+    pub fn new(cache: InputCache, fetcher: F) -> Self {
+        Self { cache, fetcher }
+    }
+
+    pub fn cache(&self) -> &InputCache {
+        &self.cache
+    }
+
+    /// Cached input of a day, fetched and cached when missing.
+    pub fn get(&self, year: u16, day: u8) -> Result<(String, Source), FetchError> {
+        let cache_error = |err: std::io::Error| FetchError::Cache(err.to_string());
+
+        if let Some(input) = self.cache.get(year, day).map_err(cache_error)? {
+            return Ok((input, Source::Cache));
+        }
+
+        let input = self.fetcher.fetch(year, day)?;
+        self.cache.store(year, day, &input).map_err(cache_error)?;
+
+        Ok((input, Source::Fetched))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputManager, Source};
+    use crate::{cache::tests::temp_cache, FetchError, Fetcher, HttpFetcher, MockServer};
+    use std::{
+        cell::Cell,
+        fs,
+        time::{Duration, Instant},
+    };
+
+    /// Fetcher counting its calls, failing for days it has no input for.
+    struct Counting {
+        calls: Cell<usize>,
+    }
+
+    impl Fetcher for Counting {
+        fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+            self.calls.set(self.calls.get() + 1);
+
+            match day {
+                1 => Ok(String::from("1000\n")),
+                _ => Err(FetchError::NotFound { year, day }),
+            }
+        }
+    }
+
+    #[test]
+    fn should_fetch_only_missing_inputs() {
+        let manager = InputManager::new(
+            temp_cache("manager"),
+            Counting {
+                calls: Cell::new(0),
+            },
+        );
+
+        assert_eq!(
+            (String::from("1000\n"), Source::Fetched),
+            manager.get(2022, 1).unwrap()
+        );
+        assert_eq!(
+            (String::from("1000\n"), Source::Cache),
+            manager.get(2022, 1).unwrap()
+        );
+        assert_eq!(1, manager.fetcher.calls.get());
+
+        assert_eq!(
+            Err(FetchError::NotFound { year: 2022, day: 2 }),
+            manager.get(2022, 2)
+        );
+        assert_eq!(None, manager.cache().get(2022, 2).unwrap());
+
+        fs::remove_dir_all(manager.cache().dir()).unwrap();
+    }
+
+    fn server() -> MockServer {
+        MockServer::start("secret")
+            .with_input(2022, 1, "1000\n2000\n")
+            .with_input(2022, 6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")
+    }
+
+    #[test]
+    fn should_fetch_and_cache_from_server() {
+        let server = server();
+        let fetcher = HttpFetcher::new(server.url(), "secret").with_min_interval(Duration::ZERO);
+        let manager = InputManager::new(temp_cache("server"), fetcher);
+
+        assert_eq!(Source::Fetched, manager.get(2022, 6).unwrap().1);
+        assert_eq!(
+            (
+                String::from("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"),
+                Source::Cache
+            ),
+            manager.get(2022, 6).unwrap()
+        );
+        assert_eq!(vec![String::from("/2022/day/6/input")], server.requests());
+
+        fs::remove_dir_all(manager.cache().dir()).unwrap();
+    }
+
+    #[test]
+    fn should_report_server_errors() {
+        let server = server();
+        let fetch = |session: &str, day| {
+            HttpFetcher::new(server.url(), session)
+                .with_min_interval(Duration::ZERO)
+                .fetch(2022, day)
+        };
+
+        assert_eq!(Err(FetchError::Unauthorized), fetch("expired", 1));
+        assert_eq!(
+            Err(FetchError::NotFound { year: 2022, day: 2 }),
+            fetch("secret", 2)
+        );
+        assert!(matches!(
+            HttpFetcher::new("http://127.0.0.1:1", "secret").fetch(2022, 1),
+            Err(FetchError::Transport(_))
+        ));
+    }
+
+    #[test]
+    fn should_be_rate_limited_by_server() {
+        let server = server().with_min_interval(Duration::from_secs(60));
+        let fetcher = HttpFetcher::new(server.url(), "secret").with_min_interval(Duration::ZERO);
+
+        assert!(fetcher.fetch(2022, 1).is_ok());
+        assert_eq!(
+            Err(FetchError::RateLimited(Some(Duration::from_secs(60)))),
+            fetcher.fetch(2022, 6)
+        );
+    }
+
+    #[test]
+    fn should_space_requests() {
+        let interval = Duration::from_millis(200);
+        let server = server().with_min_interval(interval);
+        let fetcher = HttpFetcher::new(server.url(), "secret").with_min_interval(interval);
+        let start = Instant::now();
+
+        assert!(fetcher.fetch(2022, 1).is_ok());
+        assert!(fetcher.fetch(2022, 6).is_ok());
+        assert!(start.elapsed() >= interval);
+        assert_eq!(2, server.requests().len());
+    }
+}
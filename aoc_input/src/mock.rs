@@ -0,0 +1,194 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+#[derive(Default)]
+struct State {
+    inputs: HashMap<(u16, u8), String>,
+    min_interval: Duration,
+    last_request: Option<Instant>,
+    requests: Vec<String>,
+}
+
+/// Local stand-in for the puzzle site, serving `GET /<year>/day/<day>/input` on localhost,
+/// so that fetching can be tested offline. It answers like the site does:
+/// 400 without the right session cookie, 404 for unknown puzzles,
+/// 429 (with `Retry-After`) when requests come faster than `min_interval`.
+/// Server stops when dropped.
+pub struct MockServer {
+    url: String,
+    session: String,
+    state: Arc<Mutex<State>>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start serving on a free port, accepting only `session` token.
+    pub fn start(session: &str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Unable to bind mock server.");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let state = Arc::clone(&state);
+            let stop = Arc::clone(&stop);
+            let session = session.to_string();
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        // A broken connection only fails its own request.
+                        let _ = handle(stream, &session, &state);
+                    }
+                }
+            })
+        };
+
+        Self {
+            url,
+            session: session.to_string(),
+            state,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn with_input(self, year: u16, day: u8, input: &str) -> Self {
+        self.state
+            .lock()
+            .unwrap()
+            .inputs
+            .insert((year, day), input.to_string());
+        self
+    }
+
+    pub fn with_min_interval(self, min_interval: Duration) -> Self {
+        self.state.lock().unwrap().min_interval = min_interval;
+        self
+    }
+
+    /// Base url, eg: `http://127.0.0.1:4242`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+
+    /// Path of every request received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake up the server blocked on accept.
+        let _ = TcpStream::connect(self.url.trim_start_matches("http://"));
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Read a request and write its response.
+fn handle(stream: TcpStream, session: &str, state: &Mutex<State>) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let mut cookie = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("cookie") {
+                cookie = value
+                    .split(';')
+                    .find_map(|c| c.trim().strip_prefix("session="))
+                    .map(str::to_string);
+            }
+        }
+    }
+
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or_default()
+        .to_string();
+    let (status, headers, body) = respond(&path, cookie.as_deref() == Some(session), state);
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+        status,
+        body.len(),
+        headers,
+        body
+    )?;
+    stream.flush()?;
+    stream.shutdown(Shutdown::Both)
+}
+
+/// Status line, extra headers and body of the response to a request.
+fn respond(
+    path: &str,
+    authenticated: bool,
+    state: &Mutex<State>,
+) -> (&'static str, String, String) {
+    let mut state = state.lock().unwrap();
+    state.requests.push(path.to_string());
+
+    if let Some(wait) = state
+        .last_request
+        .and_then(|last| state.min_interval.checked_sub(last.elapsed()))
+        .filter(|wait| !wait.is_zero())
+    {
+        return (
+            "429 Too Many Requests",
+            format!("Retry-After: {}\r\n", wait.as_secs_f64().ceil() as u64),
+            String::from("Too many requests.\n"),
+        );
+    }
+    state.last_request = Some(Instant::now());
+
+    let puzzle = match path.split('/').collect::<Vec<&str>>().as_slice() {
+        ["", year, "day", day, "input"] => year.parse().ok().zip(day.parse().ok()),
+        _ => None,
+    };
+
+    match puzzle.and_then(|puzzle| state.inputs.get(&puzzle)) {
+        _ if !authenticated => (
+            "400 Bad Request",
+            String::new(),
+            String::from(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+        ),
+        Some(input) => ("200 OK", String::new(), input.clone()),
+        None => (
+            "404 Not Found",
+            String::new(),
+            String::from("Please don't repeatedly request this endpoint before it unlocks!\n"),
+        ),
+    }
+}
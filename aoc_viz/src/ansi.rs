@@ -0,0 +1,108 @@
+//! Frames played in a terminal with ANSI escape codes.
+
+use crate::{Color, Frame};
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+const RESET: &str = "\x1b[0m";
+const CLEAR: &str = "\x1b[H\x1b[2J";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+fn code(color: Color) -> &'static str {
+    match color {
+        Color::Default => "\x1b[39m",
+        Color::Gray => "\x1b[90m",
+        Color::Red => "\x1b[31m",
+        Color::Green => "\x1b[32m",
+        Color::Yellow => "\x1b[33m",
+        Color::Blue => "\x1b[34m",
+        Color::Magenta => "\x1b[35m",
+        Color::Cyan => "\x1b[36m",
+    }
+}
+
+/// Frame as colored text, color codes are written only when color changes.
+pub fn to_ansi(frame: &Frame) -> String {
+    let mut text = String::new();
+
+    for row in frame.rows() {
+        let mut color = None;
+
+        for cell in row {
+            if color != Some(cell.color) {
+                text.push_str(code(cell.color));
+                color = Some(cell.color);
+            }
+            text.push(cell.char);
+        }
+        text.push_str(RESET);
+        text.push('\n');
+    }
+
+    text
+}
+
+/// Delay between frames played at `fps` frames per second.
+pub fn frame_delay(fps: f64) -> Duration {
+    Duration::from_secs_f64(1.0 / fps.max(0.01))
+}
+
+/// Play frames in terminal `out`, each one replacing the previous one, at `fps` frames per second.
+pub fn play(frames: &[Frame], out: &mut impl Write, fps: f64) -> io::Result<()> {
+    write!(out, "{}", HIDE_CURSOR)?;
+
+    for (idx, frame) in frames.iter().enumerate() {
+        write!(out, "{}{}", CLEAR, to_ansi(frame))?;
+        write!(
+            out,
+            "{}frame {}/{}{}",
+            code(Color::Gray),
+            idx + 1,
+            frames.len(),
+            RESET
+        )?;
+        out.flush()?;
+
+        if idx + 1 < frames.len() {
+            thread::sleep(frame_delay(fps));
+        }
+    }
+
+    writeln!(out, "{}", SHOW_CURSOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{play, to_ansi};
+    use crate::{Color, Frame};
+
+    #[test]
+    fn should_color_runs_of_cells() {
+        let mut frame = Frame::new();
+        frame
+            .write(0, 0, "ab", Color::Red)
+            .write(0, 2, "c", Color::Blue);
+
+        assert_eq!("\x1b[31mab\x1b[34mc\x1b[0m\n", to_ansi(&frame));
+    }
+
+    #[test]
+    fn should_play_every_frame() {
+        let mut first = Frame::new();
+        first.push_line("1", Color::Default);
+        let mut second = Frame::new();
+        second.push_line("2", Color::Default);
+        let mut out = Vec::new();
+
+        play(&[first, second], &mut out, 1000.0).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(2, out.matches("\x1b[2J").count());
+        assert!(out.contains("frame 2/2"));
+        assert!(out.ends_with("\x1b[?25h\n"));
+    }
+}
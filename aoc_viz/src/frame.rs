@@ -0,0 +1,173 @@
+/// Colors available to every output: terminal, svg and gif.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Color {
+    #[default]
+    Default,
+    Gray,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// Every color, in palette order.
+    pub const ALL: [Color; 8] = [
+        Color::Default,
+        Color::Gray,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    /// One of the bright colors picked from `seed`, so that eg: each crate letter keeps its color.
+    pub fn pick(seed: usize) -> Self {
+        Self::ALL[2 + seed % (Self::ALL.len() - 2)]
+    }
+
+    /// Red, green and blue.
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            Color::Default => [0xe6, 0xe6, 0xe6],
+            Color::Gray => [0x80, 0x80, 0x80],
+            Color::Red => [0xe0, 0x6c, 0x75],
+            Color::Green => [0x98, 0xc3, 0x79],
+            Color::Yellow => [0xe5, 0xc0, 0x7b],
+            Color::Blue => [0x61, 0xaf, 0xef],
+            Color::Magenta => [0xc6, 0x78, 0xdd],
+            Color::Cyan => [0x56, 0xb6, 0xc2],
+        }
+    }
+}
+
+/// A char with its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub char: char,
+    pub color: Color,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            char: ' ',
+            color: Color::Default,
+        }
+    }
+}
+
+/// A picture made of rows of colored chars, rows may have different lengths.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Frame {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write `text` from `row` and `column` (0-based), growing frame when needed.
+    pub fn write(&mut self, row: usize, column: usize, text: &str, color: Color) -> &mut Self {
+        if self.rows.len() <= row {
+            self.rows.resize(row + 1, Vec::new());
+        }
+
+        let cells = &mut self.rows[row];
+
+        for (idx, char) in text.chars().enumerate() {
+            if cells.len() <= column + idx {
+                cells.resize(column + idx + 1, Cell::default());
+            }
+            cells[column + idx] = Cell { char, color };
+        }
+
+        self
+    }
+
+    /// Add `text` as a new last row.
+    pub fn push_line(&mut self, text: &str, color: Color) -> &mut Self {
+        self.write(self.rows.len(), 0, text, color)
+    }
+
+    /// Same frame with `text` as a new first row, eg: the step that led to it.
+    pub fn with_caption(mut self, text: &str, color: Color) -> Self {
+        self.rows.insert(0, Vec::new());
+        self.write(0, 0, text, color);
+        self
+    }
+
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.rows
+    }
+
+    /// Length of longest row.
+    pub fn width(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Chars without colors, trailing spaces removed.
+    pub fn text(&self) -> String {
+        self.rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.char)
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A puzzle state that can be drawn, eg: day 5 stacks of crates.
+pub trait Draw {
+    fn draw(&self) -> Frame;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, Frame};
+
+    #[test]
+    fn should_grow_when_writing() {
+        let mut frame = Frame::new();
+
+        frame
+            .write(1, 2, "ab", Color::Red)
+            .push_line("c", Color::Blue);
+
+        assert_eq!("\n  ab\nc", frame.text());
+        assert_eq!((4, 3), (frame.width(), frame.height()));
+        assert_eq!(Color::Red, frame.rows()[1][3].color);
+    }
+
+    #[test]
+    fn should_add_caption_above() {
+        let mut frame = Frame::new();
+        frame.push_line("[A]", Color::Default);
+
+        assert_eq!(
+            "move 1\n[A]",
+            frame.with_caption("move 1", Color::Yellow).text()
+        );
+    }
+
+    #[test]
+    fn should_pick_bright_colors() {
+        for seed in 0..20 {
+            assert!(!matches!(Color::pick(seed), Color::Default | Color::Gray));
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Frames as an animated gif, chars are drawn with a 3x5 pixels font.
+
+use crate::{
+    font::{glyph, GLYPH_HEIGHT, GLYPH_WIDTH},
+    Color, Frame,
+};
+use std::{collections::HashMap, time::Duration};
+
+/// Pixels of a glyph are drawn as squares of this size.
+const SCALE: usize = 2;
+/// Size of a char in pixels, a pixel of space around glyph included.
+const CHAR_WIDTH: usize = (GLYPH_WIDTH + 1) * SCALE;
+const CHAR_HEIGHT: usize = (GLYPH_HEIGHT + 1) * SCALE;
+const BACKGROUND: [u8; 3] = [0x1e, 0x1e, 0x1e];
+/// Palette is: background then [`Color::ALL`], padded to 16 colors.
+const PALETTE_BITS: u8 = 4;
+/// Codes of gif LZW compression are at most 12 bits.
+const MAX_CODES: u16 = 4096;
+
+fn palette_index(color: Color) -> u8 {
+    1 + Color::ALL.iter().position(|c| *c == color).unwrap_or(0) as u8
+}
+
+/// Indexes in palette of each pixel of `frame`, drawn on a canvas of `width` x `height` pixels.
+fn pixels(frame: &Frame, width: usize, height: usize) -> Vec<u8> {
+    let mut pixels = vec![0u8; width * height];
+
+    for (row_idx, row) in frame.rows().iter().enumerate() {
+        for (column, cell) in row.iter().enumerate() {
+            let glyph = glyph(cell.char);
+            let color = palette_index(cell.color);
+
+            for (y, bits) in glyph.iter().enumerate() {
+                for x in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - x)) == 0 {
+                        continue;
+                    }
+
+                    for dy in 0..SCALE {
+                        for dx in 0..SCALE {
+                            let px = column * CHAR_WIDTH + SCALE / 2 + x * SCALE + dx;
+                            let py = row_idx * CHAR_HEIGHT + SCALE / 2 + y * SCALE + dy;
+                            pixels[py * width + px] = color;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pixels
+}
+
+/// Writes codes of variable size, least significant bit first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Gif flavor of LZW compression of palette indexes of `min_code_size` bits.
+fn lzw(indexes: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut writer = BitWriter::default();
+    let mut codes = HashMap::<(u16, u8), u16>::new();
+    let mut next = end + 1;
+    let mut size = min_code_size + 1;
+
+    writer.write(clear, size);
+
+    let Some((first, rest)) = indexes.split_first() else {
+        writer.write(end, size);
+        return writer.finish();
+    };
+    let mut prefix = *first as u16;
+
+    for index in rest {
+        if let Some(code) = codes.get(&(prefix, *index)) {
+            prefix = *code;
+            continue;
+        }
+
+        writer.write(prefix, size);
+
+        if next == MAX_CODES {
+            writer.write(clear, size);
+            codes.clear();
+            next = end + 1;
+            size = min_code_size + 1;
+        } else {
+            codes.insert((prefix, *index), next);
+            next += 1;
+
+            // Decoder adds codes one step later, so it grows code size one code later.
+            if next > 1 << size && size < 12 {
+                size += 1;
+            }
+        }
+        prefix = *index as u16;
+    }
+
+    writer.write(prefix, size);
+    writer.write(end, size);
+    writer.finish()
+}
+
+/// Gif data sub-blocks: chunks of at most 255 bytes prefixed by their length, ended by an empty one.
+fn sub_blocks(data: &[u8]) -> Vec<u8> {
+    let mut blocks = Vec::with_capacity(data.len() + data.len() / 255 + 2);
+
+    for chunk in data.chunks(255) {
+        blocks.push(chunk.len() as u8);
+        blocks.extend_from_slice(chunk);
+    }
+    blocks.push(0);
+
+    blocks
+}
+
+/// Frames as an animated gif looping forever, each frame shown for `delay`.
+/// Canvas fits largest frame.
+pub fn encode(frames: &[Frame], delay: Duration) -> Vec<u8> {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0).max(1) * CHAR_WIDTH;
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0).max(1) * CHAR_HEIGHT;
+    // Delay is in hundredths of second.
+    let delay = (delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+    let mut gif = b"GIF89a".to_vec();
+
+    // Logical screen: size, global palette of 2^PALETTE_BITS colors, background color 0.
+    gif.extend_from_slice(&(width as u16).to_le_bytes());
+    gif.extend_from_slice(&(height as u16).to_le_bytes());
+    gif.extend_from_slice(&[0xf0 | (PALETTE_BITS - 1), 0, 0]);
+
+    let mut palette = vec![BACKGROUND];
+    palette.extend(Color::ALL.iter().map(Color::rgb));
+    palette.resize(1 << PALETTE_BITS, [0, 0, 0]);
+    gif.extend(palette.into_iter().flatten());
+
+    // Loop forever.
+    gif.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for frame in frames {
+        // Graphic control: delay, no transparency.
+        gif.extend_from_slice(&[0x21, 0xf9, 0x04, 0x00]);
+        gif.extend_from_slice(&delay.to_le_bytes());
+        gif.extend_from_slice(&[0x00, 0x00]);
+
+        // Image at 0,0 covering the whole canvas, using global palette.
+        gif.push(0x2c);
+        gif.extend_from_slice(&[0, 0, 0, 0]);
+        gif.extend_from_slice(&(width as u16).to_le_bytes());
+        gif.extend_from_slice(&(height as u16).to_le_bytes());
+        gif.push(0x00);
+
+        gif.push(PALETTE_BITS);
+        gif.extend(sub_blocks(&lzw(
+            &pixels(frame, width, height),
+            PALETTE_BITS,
+        )));
+    }
+
+    gif.push(0x3b);
+    gif
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode, lzw, palette_index, pixels, sub_blocks, CHAR_HEIGHT, CHAR_WIDTH};
+    use crate::{Color, Frame};
+    use rstest::rstest;
+    use std::time::Duration;
+
+    /// Gif LZW decompression, as a decoder reading the gif would do it.
+    fn unlzw(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1u16 << min_code_size;
+        let end = clear + 1;
+        let reset = || -> Vec<Vec<u8>> { (0..clear + 2).map(|c| vec![c as u8]).collect() };
+        let mut table = reset();
+        let mut size = min_code_size + 1;
+        let mut previous: Option<Vec<u8>> = None;
+        let mut indexes = Vec::new();
+        let (mut buffer, mut bits, mut bytes) = (0u32, 0u8, data.iter());
+
+        loop {
+            while bits < size {
+                buffer |= (*bytes.next().expect("Missing end code.") as u32) << bits;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << size) - 1)) as u16;
+            buffer >>= size;
+            bits -= size;
+
+            if code == clear {
+                table = reset();
+                size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return indexes;
+            }
+
+            let entry = match (table.get(code as usize), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("Unknown first code: {}.", code),
+            };
+
+            if let Some(previous) = previous {
+                table.push([previous, vec![entry[0]]].concat());
+
+                if table.len() == 1 << size && size < 12 {
+                    size += 1;
+                }
+            }
+            indexes.extend_from_slice(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![3])]
+    #[case(vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1])]
+    #[case((0..5000).map(|i| (i % 7 + i / 300 % 3) as u8).collect())]
+    #[case((0..40000u32).map(|i| (i.wrapping_mul(2654435761) >> 28) as u8).collect())]
+    fn should_compress_without_loss(#[case] indexes: Vec<u8>) {
+        assert_eq!(indexes, unlzw(&lzw(&indexes, 4), 4));
+    }
+
+    #[test]
+    fn should_split_sub_blocks() {
+        let blocks = sub_blocks(&[7; 300]);
+
+        assert_eq!(300 + 3, blocks.len());
+        assert_eq!(255, blocks[0]);
+        assert_eq!(45, blocks[256]);
+        assert_eq!(0, *blocks.last().unwrap());
+    }
+
+    #[test]
+    fn should_draw_chars_with_their_color() {
+        let mut frame = Frame::new();
+        frame.write(0, 1, "-", Color::Red);
+        let pixels = pixels(&frame, 2 * CHAR_WIDTH, CHAR_HEIGHT);
+
+        // "-" is the middle row of glyph, in second char.
+        let y = 1 + 2 * 2;
+        assert!(pixels[y * 2 * CHAR_WIDTH..(y + 1) * 2 * CHAR_WIDTH]
+            .iter()
+            .skip(CHAR_WIDTH)
+            .any(|p| *p == palette_index(Color::Red)));
+        assert_eq!(
+            3 * 2 * 2,
+            pixels.iter().filter(|p| **p != 0).count(),
+            "3 pixels of 2x2."
+        );
+    }
+
+    #[test]
+    fn should_encode_animation() {
+        let mut first = Frame::new();
+        first.push_line("[A]", Color::Red);
+        let mut second = first.clone();
+        second.push_line("[B]", Color::Blue);
+
+        let gif = encode(&[first, second], Duration::from_millis(250));
+
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(
+            (3 * CHAR_WIDTH) as u16,
+            u16::from_le_bytes([gif[6], gif[7]])
+        );
+        assert_eq!(
+            (2 * CHAR_HEIGHT) as u16,
+            u16::from_le_bytes([gif[8], gif[9]])
+        );
+        assert_eq!(
+            2,
+            gif.windows(4)
+                .filter(|w| w == &[0x21, 0xf9, 0x04, 0x00])
+                .count()
+        );
+        // 25 hundredths of second.
+        assert!(gif.windows(6).any(|w| w == [0x21, 0xf9, 0x04, 0x00, 25, 0]));
+        assert_eq!(Some(&0x3b), gif.last());
+    }
+}
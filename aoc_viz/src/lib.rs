@@ -0,0 +1,10 @@
+//! Visualizations of puzzles: days [`Draw`] their state as [`Frame`]s of colored chars,
+//! that can be played in a terminal ([`ansi`]) or exported as [`svg`] frames and animated [`gif`].
+
+pub mod ansi;
+mod font;
+mod frame;
+pub mod gif;
+pub mod svg;
+
+pub use frame::{Cell, Color, Draw, Frame};
@@ -0,0 +1,107 @@
+//! Frames as svg pictures, one file per frame.
+
+use crate::{Color, Frame};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Size of a char in pixels.
+const CHAR_WIDTH: usize = 10;
+const CHAR_HEIGHT: usize = 18;
+const BACKGROUND: &str = "#1e1e1e";
+
+fn hex(color: Color) -> String {
+    let [r, g, b] = color.rgb();
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Frame as an svg picture, with a `<text>` per row and a `<tspan>` per run of cells of the same color.
+pub fn to_svg(frame: &Frame) -> String {
+    let width = frame.width() * CHAR_WIDTH;
+    let height = frame.height() * CHAR_HEIGHT;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n\
+         <g font-family=\"monospace\" font-size=\"16\" xml:space=\"preserve\">\n",
+        w = width,
+        h = height,
+        bg = BACKGROUND
+    );
+
+    for (idx, row) in frame.rows().iter().enumerate() {
+        // Column of current run of cells.
+        let mut column = 0;
+        svg.push_str(&format!("<text y=\"{}\">", (idx + 1) * CHAR_HEIGHT - 4));
+
+        for run in row.chunk_by(|a, b| a.color == b.color) {
+            let text = run.iter().map(|cell| cell.char).collect::<String>();
+            svg.push_str(&format!(
+                "<tspan x=\"{}\" fill=\"{}\">{}</tspan>",
+                column * CHAR_WIDTH,
+                hex(run[0].color),
+                escape(&text)
+            ));
+            column += run.len();
+        }
+        svg.push_str("</text>\n");
+    }
+
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+/// Write each frame in `dir` as: `frame_0001.svg`, `frame_0002.svg`... Returns written files.
+pub fn write_frames(frames: &[Frame], dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+
+    frames
+        .iter()
+        .enumerate()
+        .map(|(idx, frame)| {
+            let path = dir.join(format!("frame_{:04}.svg", idx + 1));
+            fs::write(&path, to_svg(frame)).map(|_| path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_svg, write_frames};
+    use crate::{Color, Frame};
+    use std::fs;
+
+    #[test]
+    fn should_draw_a_span_per_color() {
+        let mut frame = Frame::new();
+        frame
+            .write(0, 0, "[A]", Color::Red)
+            .write(0, 4, "<B>", Color::Blue);
+        let svg = to_svg(&frame);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"70\" height=\"18\""));
+        assert!(svg.contains("<tspan x=\"0\" fill=\"#e06c75\">[A]</tspan>"));
+        assert!(svg.contains("<tspan x=\"30\" fill=\"#e6e6e6\"> </tspan>"));
+        assert!(svg.contains("<tspan x=\"40\" fill=\"#61afef\">&lt;B&gt;</tspan>"));
+    }
+
+    #[test]
+    fn should_write_a_file_per_frame() {
+        let dir = std::env::temp_dir().join(format!("aoc_viz_svg_{}", std::process::id()));
+        let files = write_frames(&[Frame::new(), Frame::new()], &dir).unwrap();
+
+        assert_eq!(
+            vec![dir.join("frame_0001.svg"), dir.join("frame_0002.svg")],
+            files
+        );
+        assert!(fs::read_to_string(&files[1]).unwrap().ends_with("</svg>\n"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
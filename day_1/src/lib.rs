@@ -0,0 +1,217 @@
+//! [Day 1: Calorie Counting](https://adventofcode.com/2022/day/1).
+//! Input is made of groups of calories separated by an empty line, one group per elf.
+
+use aoc_common::{parse::Cursor, Error, Param, Params, ParseError, Solution};
+use std::io::prelude::*;
+use tracing::{debug, trace};
+
+/// Returns the value of the sequence with biggest sum.
+/// Accept a reader where for each single line is admited only one integer or an empty line.
+/// Each sequence ends by an empty line.
+pub fn get_maximum(reader: &mut impl BufRead) -> Result<usize, Error> {
+    let max = get_calories_of_each_elf(reader)?
+        .into_iter()
+        .max()
+        .unwrap_or(0);
+    debug!(max, "maximum");
+
+    Ok(max)
+}
+
+/// Returns the sum of three sequences with biggest sum.
+/// Accept a reader where for each single line is admited only one integer or an empty line.
+/// Each sequence ends by an empty line.
+pub fn get_sum_of_the_n_maximum(reader: &mut impl BufRead, n: usize) -> Result<usize, Error> {
+    get_sum_of_the_n_highest(&get_calories_of_each_elf(reader)?, n)
+}
+
+/// Sum of the `n` highest calories carried by an elf.
+fn get_sum_of_the_n_highest(calories: &[usize], n: usize) -> Result<usize, Error> {
+    let mut highest_values = vec![0_usize; n];
+
+    for value in calories {
+        update_highest_values(&mut highest_values, *value);
+    }
+    debug!(?highest_values, "highest values");
+    highest_values
+        .iter()
+        .try_fold(0_usize, |total, value| total.checked_add(*value))
+        .ok_or_else(|| Error::NoAnswer(String::from("Sum of highest calories is too large.")))
+}
+
+/// Calories carried by each elf, read line by line. Elves are separated by empty lines, many in a row count as one.
+pub fn get_calories_of_each_elf(reader: &mut impl BufRead) -> Result<Vec<usize>, Error> {
+    let mut calories = Vec::new();
+    // First line and running sum of current elf, none between elves.
+    let mut elf: Option<(usize, usize)> = None;
+
+    for (idx, l) in reader.lines().enumerate() {
+        let line = l?;
+
+        // if line is empty we have reached end of sequence.
+        if line.is_empty() {
+            if let Some((first_line, sum)) = elf.take() {
+                debug!(line = first_line, calories = sum, "elf done");
+                calories.push(sum);
+            }
+            continue;
+        }
+
+        let (first_line, sum) = elf.unwrap_or((idx + 1, 0));
+        let sum = add_calories(sum, &line, idx + 1)?;
+        trace!(line = idx + 1, sum, "running sum");
+        elf = Some((first_line, sum));
+    }
+    // Last elf isn't followed by an empty line.
+    if let Some((first_line, sum)) = elf {
+        debug!(line = first_line, calories = sum, "elf done");
+        calories.push(sum);
+    }
+
+    Ok(calories)
+}
+
+/// Add calories of a non empty line to the sum of current sequence.
+fn add_calories(sum: usize, line: &str, line_number: usize) -> Result<usize, ParseError> {
+    let mut cursor = Cursor::new(line, line_number);
+    let (calories, span) = cursor.spanned(Cursor::integer::<usize>)?;
+
+    cursor
+        .end()
+        .map_err(|err| err.with_message("Expected only calories on line."))?;
+
+    sum.checked_add(calories)
+        .ok_or_else(|| cursor.error_at(span, "Sum of calories is too large."))
+}
+
+/// Give a sorted array (asc) add new_value if it's biggest than first element of array.
+fn update_highest_values(arr: &mut [usize], new_value: usize) {
+    if arr.is_empty() || new_value <= arr[0] {
+        return;
+    }
+    arr[0] = new_value;
+    arr.sort();
+}
+
+/// Part A: calories carried by the elf carrying the most, part B: calories carried by the `top` (3) elves.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+    const PARAMS: &'static [Param] = &[Param {
+        name: "top",
+        default: 3,
+        min: 1,
+        about: "Number of elves carrying the most calories summed by part B.",
+    }];
+
+    type Input = Vec<usize>;
+
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+        get_calories_of_each_elf(reader)
+    }
+
+    fn part_a(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        Ok(input.iter().max().unwrap_or(&0).to_string())
+    }
+
+    fn part_b(input: &Self::Input, params: &Params) -> Result<String, Error> {
+        // More elves than there are count as all of them.
+        let top = params.get("top").min(input.len() as u64) as usize;
+
+        get_sum_of_the_n_highest(input, top).map(|sum| sum.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{get_maximum, get_sum_of_the_n_maximum, update_highest_values};
+    use aoc_common::{Error, ParseError};
+    use rstest::*;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", 1001)]
+    #[case("./src/test_inputs/test_input_2.txt", 35)]
+    #[case("./src/test_inputs/test_input_3.txt", 6)]
+    fn get_maximum_success(#[case] path: &str, #[case] expected: usize) {
+        // Retrieve file.
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(expected, get_maximum(&mut reader).unwrap());
+    }
+
+    #[rstest]
+    #[case(&mut [1_usize,2,3,4], 2, &[2_usize,2,3,4])]
+    #[case(&mut [0_usize, 0, 0], 2, &[0_usize, 0, 2])]
+    #[case(&mut [0_usize, 0, 2], 2, &[0_usize, 2, 2])]
+    #[case(&mut [0_usize, 2, 2], 2, &[2_usize, 2, 2])]
+    fn update_highest_values_should_add_item(
+        #[case] input_array: &mut [usize],
+        #[case] new_value: usize,
+        #[case] output_array: &[usize],
+    ) {
+        update_highest_values(input_array, new_value);
+
+        assert_eq!(&input_array, &output_array);
+    }
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_4.txt", 82)]
+    #[case("./src/test_inputs/test_input_5.txt", 395)]
+    #[case("./src/test_inputs/test_input_6.txt", 0)]
+    fn get_sum_of_the_n_maximum_success(#[case] path: &str, #[case] expected: usize) {
+        // Retrieve file.
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(expected, get_sum_of_the_n_maximum(&mut reader, 3).unwrap());
+    }
+
+    #[rstest]
+    #[case("100\n2x0\n\n300", 2, "x0")]
+    #[case("100\n\n-5", 3, "-5")]
+    fn should_point_to_invalid_line(#[case] input: &str, #[case] line: usize, #[case] text: &str) {
+        for result in [
+            get_maximum(&mut input.as_bytes()),
+            get_sum_of_the_n_maximum(&mut input.as_bytes(), 3),
+        ] {
+            match result {
+                Err(Error::Parse(ParseError {
+                    line: err_line,
+                    text: err_text,
+                    ..
+                })) => {
+                    assert_eq!(line, err_line);
+                    assert_eq!(text, err_text);
+                }
+                other => panic!("Expected a parse error, got: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn should_reject_too_many_calories() {
+        let input = format!("1\n{}\n\n2", usize::MAX);
+
+        for result in [
+            get_maximum(&mut input.as_bytes()),
+            get_sum_of_the_n_maximum(&mut input.as_bytes(), 3),
+        ] {
+            assert!(matches!(
+                result,
+                Err(Error::Parse(ParseError { line: 2, .. }))
+            ));
+        }
+
+        let input = format!("{}\n\n{}", usize::MAX, usize::MAX);
+        assert_eq!(usize::MAX, get_maximum(&mut input.as_bytes()).unwrap());
+        assert!(matches!(
+            get_sum_of_the_n_maximum(&mut input.as_bytes(), 3),
+            Err(Error::NoAnswer(_))
+        ));
+    }
+}
@@ -0,0 +1,427 @@
+//! [Day 2: Rock Paper Scissors](https://adventofcode.com/2022/day/2).
+//! Input is a strategy guide, one round per line: "A Y".
+
+use aoc_common::{parse::Cursor, Error, Params, ParseError, Solution};
+use std::io::BufRead;
+use tracing::debug;
+
+/// Figure played in a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RockPaperScissorType {
+    Rock,
+    Scissor,
+    Paper,
+}
+
+/// A figure played in a round, with figures it loses and wins against.
+#[derive(Debug)]
+pub struct RockPaperScissorFigure {
+    pub figure: RockPaperScissorType,
+    pub who_defeats_me: RockPaperScissorType,
+    pub who_i_defeat: RockPaperScissorType,
+}
+
+impl RockPaperScissorFigure {
+    /// Returns None when figure name is not one of: A,B,C,X,Y,Z.
+    pub fn new(figure_name: &char) -> Option<Self> {
+        match figure_name {
+            // A and X maps Rock.
+            'A' | 'X' => Some(Self::generate_from_type(&RockPaperScissorType::Rock)),
+            // B and Y maps Paper.
+            'B' | 'Y' => Some(Self::generate_from_type(&RockPaperScissorType::Paper)),
+            // C and Z maps Scissor.
+            'C' | 'Z' => Some(Self::generate_from_type(&RockPaperScissorType::Scissor)),
+            _ => None,
+        }
+    }
+
+    /// Figure of type t, with figures it loses and wins against.
+    pub fn generate_from_type(t: &RockPaperScissorType) -> Self {
+        match t {
+            RockPaperScissorType::Rock => RockPaperScissorFigure {
+                figure: RockPaperScissorType::Rock,
+                who_defeats_me: RockPaperScissorType::Paper,
+                who_i_defeat: RockPaperScissorType::Scissor,
+            },
+            RockPaperScissorType::Scissor => RockPaperScissorFigure {
+                figure: RockPaperScissorType::Scissor,
+                who_defeats_me: RockPaperScissorType::Rock,
+                who_i_defeat: RockPaperScissorType::Paper,
+            },
+            RockPaperScissorType::Paper => RockPaperScissorFigure {
+                figure: RockPaperScissorType::Paper,
+                who_defeats_me: RockPaperScissorType::Scissor,
+                who_i_defeat: RockPaperScissorType::Rock,
+            },
+        }
+    }
+}
+
+/// Points earned by each player in a round: figure value plus outcome points.
+#[derive(Debug)]
+pub struct RockPaperScissorGame {
+    pub player_one_points: u8,
+    pub player_two_points: u8,
+}
+
+impl RockPaperScissorGame {
+    pub const DRAW_POINTS: u8 = 3;
+    pub const VICTORY_POINTS: u8 = 6;
+
+    /// Play a round.
+    pub fn new(player_one: &RockPaperScissorFigure, player_two: &RockPaperScissorFigure) -> Self {
+        if player_one.figure == player_two.figure {
+            let points =
+                Self::get_value_of_rockpaperscissor_type(&player_one.figure) + Self::DRAW_POINTS;
+
+            return RockPaperScissorGame {
+                player_one_points: points,
+                player_two_points: points,
+            };
+        }
+
+        let player_one_figure_points = Self::get_value_of_rockpaperscissor_type(&player_one.figure);
+        let player_two_figure_points = Self::get_value_of_rockpaperscissor_type(&player_two.figure);
+
+        if player_two.who_defeats_me == player_one.figure {
+            return RockPaperScissorGame {
+                player_one_points: player_one_figure_points + Self::VICTORY_POINTS,
+                player_two_points: player_two_figure_points,
+            };
+        }
+
+        RockPaperScissorGame {
+            player_one_points: player_one_figure_points,
+            player_two_points: player_two_figure_points + Self::VICTORY_POINTS,
+        }
+    }
+
+    /// Play a round where player two chooses the figure that gives the desired verdict.
+    pub fn generate_from_desired_verdict(
+        player_one: &RockPaperScissorFigure,
+        desired_verdict: &Verdict,
+    ) -> RockPaperScissorGame {
+        let player_two = match desired_verdict {
+            // Player two has to lose.
+            Verdict::Lose => RockPaperScissorFigure::generate_from_type(&player_one.who_i_defeat),
+            // We need a draw.
+            Verdict::Draw => RockPaperScissorFigure::generate_from_type(&player_one.figure),
+            // Player two has to win.
+            Verdict::Win => RockPaperScissorFigure::generate_from_type(&player_one.who_defeats_me),
+        };
+
+        Self::new(player_one, &player_two)
+    }
+
+    /// Points given by a figure: 1 for rock, 2 for paper and 3 for scissor.
+    pub fn get_value_of_rockpaperscissor_type(t: &RockPaperScissorType) -> u8 {
+        match t {
+            RockPaperScissorType::Rock => 1,
+            RockPaperScissorType::Paper => 2,
+            RockPaperScissorType::Scissor => 3,
+        }
+    }
+}
+
+/// Outcome of a round for player two, wanted by the strategy guide of part B.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Verdict {
+    /// Returns None when verdict name is not one of: X,Y,Z.
+    /// X means lose, Y means draw and Z means win.
+    pub fn new(verdict_name: &char) -> Option<Self> {
+        match verdict_name {
+            'X' => Some(Self::Lose),
+            'Y' => Some(Self::Draw),
+            'Z' => Some(Self::Win),
+            _ => None,
+        }
+    }
+}
+
+/// A line of the strategy guide: player one figure, then a figure (part A) or a verdict (part B) for player two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub player_one: RockPaperScissorType,
+    pub player_two: RockPaperScissorType,
+    /// Chars of the line, eg: ('A', 'Z'). Only X,Y,Z are verdicts: part B fails on others.
+    pub chars: (char, char),
+}
+
+/// Returns the sum of total points earned in each match by player two.
+/// Accept a reader where for each single line represent a game of two players in rock scissor paper.
+/// Allowed moves for player one: A | B | C.
+/// Allowed moves for player two: X | Y | Z.
+/// Example line: "A Z".
+pub fn get_total_points_of_player_two(reader: &mut impl BufRead) -> Result<u32, Error> {
+    Ok(total_points_of_player_two(&get_rounds(reader)?))
+}
+
+pub fn get_total_points_of_player_two_from_desired_verdict(
+    reader: &mut impl BufRead,
+) -> Result<u32, Error> {
+    Ok(total_points_of_player_two_from_desired_verdict(
+        &get_rounds(reader)?,
+    )?)
+}
+
+/// Rounds of the strategy guide, one per line.
+pub fn get_rounds(reader: &mut impl BufRead) -> Result<Vec<Round>, Error> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, l)| Ok(get_round(&l?, idx + 1)?))
+        .collect()
+}
+
+/// Sum of points of player two playing the figures of the guide.
+fn total_points_of_player_two(rounds: &[Round]) -> u32 {
+    rounds
+        .iter()
+        .enumerate()
+        .map(|(idx, round)| {
+            let game = RockPaperScissorGame::new(
+                &RockPaperScissorFigure::generate_from_type(&round.player_one),
+                &RockPaperScissorFigure::generate_from_type(&round.player_two),
+            );
+            debug!(
+                round = idx + 1,
+                player_one = ?round.player_one,
+                player_two = ?round.player_two,
+                points = game.player_two_points,
+                "round"
+            );
+
+            game.player_two_points as u32
+        })
+        .sum()
+}
+
+/// Sum of points of player two playing to get the verdicts of the guide.
+fn total_points_of_player_two_from_desired_verdict(rounds: &[Round]) -> Result<u32, ParseError> {
+    rounds.iter().enumerate().try_fold(0, |sum, (idx, round)| {
+        let (first, third) = round.chars;
+        let verdict = Verdict::new(&third).ok_or_else(|| {
+            ParseError::new(
+                "Only values: X | Y | Z are allowed.",
+                idx + 1,
+                &format!("{} {}", first, third),
+                3,
+                1,
+            )
+        })?;
+        let game = RockPaperScissorGame::generate_from_desired_verdict(
+            &RockPaperScissorFigure::generate_from_type(&round.player_one),
+            &verdict,
+        );
+        debug!(
+            round = idx + 1,
+            player_one = ?round.player_one,
+            ?verdict,
+            points = game.player_two_points,
+            "round"
+        );
+
+        Ok(sum + game.player_two_points as u32)
+    })
+}
+
+/// From a line like "A Z" returns the round.
+fn get_round(line: &str, line_number: usize) -> Result<Round, ParseError> {
+    let (first, third) = get_round_chars(line, line_number)?;
+
+    // Player one figure is the first char in line.
+    let player_one = get_figure(line, line_number, first, 1)?;
+
+    // Player two figure, or verdict of the game, is the third char in line.
+    let player_two = get_figure(line, line_number, third, 3)?;
+
+    Ok(Round {
+        player_one: player_one.figure,
+        player_two: player_two.figure,
+        chars: (first, third),
+    })
+}
+
+/// From a line like "A Z" returns first and third char.
+fn get_round_chars(line: &str, line_number: usize) -> Result<(char, char), ParseError> {
+    let expected = "Expected a round like: \"A Z\".";
+    let mut cursor = Cursor::new(line, line_number);
+    let first = cursor.char().map_err(|err| err.with_message(expected))?;
+
+    cursor.tag(" ").map_err(|err| match cursor.is_empty() {
+        true => err.with_message(expected),
+        false => err.with_message("Expected a space between figures."),
+    })?;
+
+    let third = cursor.char().map_err(|err| err.with_message(expected))?;
+
+    cursor
+        .end()
+        .map_err(|err| err.with_message("Unexpected text after round."))?;
+
+    Ok((first, third))
+}
+
+/// Get figure from char at given column of line.
+fn get_figure(
+    line: &str,
+    line_number: usize,
+    figure_name: char,
+    column: usize,
+) -> Result<RockPaperScissorFigure, ParseError> {
+    RockPaperScissorFigure::new(&figure_name).ok_or_else(|| {
+        ParseError::new(
+            "Only: A,B,C,X,Y,Z are allowed.",
+            line_number,
+            line,
+            column,
+            1,
+        )
+    })
+}
+
+/// Part A: player two plays the figure of the guide, part B: player two plays to get the verdict of the guide.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input = Vec<Round>;
+
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+        get_rounds(reader)
+    }
+
+    fn part_a(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        Ok(total_points_of_player_two(input).to_string())
+    }
+
+    fn part_b(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        Ok(total_points_of_player_two_from_desired_verdict(input)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        get_total_points_of_player_two, get_total_points_of_player_two_from_desired_verdict,
+        RockPaperScissorFigure, RockPaperScissorGame, Verdict,
+    };
+    use aoc_common::{Error, ParseError};
+    use rstest::rstest;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    #[rstest]
+    #[case("A", "Y", 8)]
+    #[case("B", "X", 1)]
+    #[case("C", "Z", 6)]
+    fn should_calculate_of_player_two(
+        #[case] player_one: char,
+        #[case] player_two: char,
+        #[case] points: u8,
+    ) {
+        let player_one_figure = RockPaperScissorFigure::new(&player_one).unwrap();
+        let player_two_figure = RockPaperScissorFigure::new(&player_two).unwrap();
+
+        assert_eq!(
+            points,
+            RockPaperScissorGame::new(&player_one_figure, &player_two_figure).player_two_points
+        );
+    }
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", 15)]
+    fn should_calculate_total_points_of_player_two(#[case] path: &str, #[case] expected: u32) {
+        // Retrieve file.
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(
+            expected,
+            get_total_points_of_player_two(&mut reader).unwrap()
+        )
+    }
+
+    #[rstest]
+    #[case('A', 'Y', 4)]
+    #[case('B', 'X', 1)]
+    #[case('C', 'Z', 7)]
+    fn should_calculate_points_of_player_two_from_desired_output(
+        #[case] player_one: char,
+        #[case] desired_output: char,
+        #[case] expected: u8,
+    ) {
+        let player_one_figure = RockPaperScissorFigure::new(&player_one).unwrap();
+
+        assert_eq!(
+            expected,
+            RockPaperScissorGame::generate_from_desired_verdict(
+                &player_one_figure,
+                &Verdict::new(&desired_output).unwrap()
+            )
+            .player_two_points
+        )
+    }
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", 12)]
+    fn should_calculate_total_points_of_player_two_from_desired_output(
+        #[case] path: &str,
+        #[case] expected: u32,
+    ) {
+        // Retrieve file.
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(
+            expected,
+            get_total_points_of_player_two_from_desired_verdict(&mut reader).unwrap()
+        )
+    }
+
+    #[rstest]
+    #[case("A Y\nD X", 2, 1, "D")]
+    #[case("A Y\nB W", 2, 3, "W")]
+    #[case("A Y\nB", 2, 2, "")]
+    #[case("A Y\nBX", 2, 2, "X")]
+    #[case("A Y\n\nC Z", 2, 1, "")]
+    #[case("A Y\nB X Z", 2, 4, " Z")]
+    fn should_point_to_invalid_round(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        match get_total_points_of_player_two(&mut input.as_bytes()) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(line, err.line);
+                assert_eq!(column, err.column);
+                assert_eq!(text, err.text);
+            }
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+
+    #[rstest]
+    fn should_reject_invalid_verdict() {
+        let err = get_total_points_of_player_two_from_desired_verdict(&mut "A Y\nB C".as_bytes())
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::Parse(ParseError {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+}
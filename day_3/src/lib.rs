@@ -0,0 +1,411 @@
+//! [Day 3: Rucksack Reorganization](https://adventofcode.com/2022/day/3).
+//! Input is a rucksack per line, each item is a char: a..z and A..Z.
+
+use aoc_common::{Error, Param, Params, ParseError, Solution};
+use std::io::BufRead;
+use tracing::debug;
+
+const RANGE_LOWER_CASE_ASCII: (u8, u8) = (97, 122);
+const RANGE_UPPER_CASE_ASCII: (u8, u8) = (65, 90);
+
+/// Items of a rucksack, a line of input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    /// Part A splits them in two compartments, so it fails on an odd number of items.
+    pub items: String,
+}
+
+/// From a reader where each line is a string representing a rucksacks,
+/// calculates priority of all content by summing priority of each rucksack.
+/// Get more info on input by reading this doc: <https://adventofcode.com/2022/day/3>. (Part1)
+pub fn get_total_priority(reader: &mut impl BufRead) -> Result<u32, Error> {
+    Ok(total_priority(&get_rucksacks(reader)?)?)
+}
+
+/// From a reader where each line is a string representing a rucksacks,
+/// calculates priority of all content by summing priority of rucksacks in chunks of n.
+/// Get more info on input by reading this doc: <https://adventofcode.com/2022/day/3>. (Part2)
+pub fn get_total_priority_by_group(
+    reader: &mut impl BufRead,
+    chunk_size: usize,
+) -> Result<u32, Error> {
+    Ok(total_priority_by_group(&get_rucksacks(reader)?, chunk_size))
+}
+
+/// Rucksacks of the input, one per line.
+pub fn get_rucksacks(reader: &mut impl BufRead) -> Result<Vec<Rucksack>, Error> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, l)| {
+            let items = l?;
+            check_content(&items, idx + 1)?;
+
+            Ok(Rucksack { items })
+        })
+        .collect()
+}
+
+/// Sum of priorities of the item found in both compartments of each rucksack.
+fn total_priority(rucksacks: &[Rucksack]) -> Result<u32, ParseError> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .try_fold(0, |sum, (idx, rucksack)| {
+            // Each compartment must have the same number of items.
+            if rucksack.items.len() % 2 != 0 {
+                return Err(ParseError::whole_line(
+                    "A rucksack has to contain an even number of items.",
+                    idx + 1,
+                    &rucksack.items,
+                ));
+            }
+
+            let priority = calculate_priority_from_content(&rucksack.items);
+            debug!(
+                rucksack = idx + 1,
+                item = ?get_item_from_priority(priority),
+                priority,
+                "shared item"
+            );
+
+            Ok(sum + priority)
+        })
+}
+
+/// Sum of priorities of the badge found in each group of `chunk_size` rucksacks.
+fn total_priority_by_group(rucksacks: &[Rucksack], chunk_size: usize) -> u32 {
+    rucksacks
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(group, chunk)| {
+            let contents = chunk
+                .iter()
+                .map(|rucksack| rucksack.items.as_str())
+                .collect::<Vec<&str>>();
+
+            let priority = calculate_priority_from_contents(&contents);
+            debug!(
+                group = group + 1,
+                badge = ?get_item_from_priority(priority),
+                priority,
+                "badge"
+            );
+
+            priority
+        })
+        .sum()
+}
+
+/// Check that a rucksack content is made only by items with a priority.
+fn check_content(content: &str, line_number: usize) -> Result<(), ParseError> {
+    match content
+        .chars()
+        .position(|c| get_char_alphabetical_index(&c).is_none())
+    {
+        Some(position) => Err(ParseError::new(
+            "Only items: a..z and A..Z are allowed.",
+            line_number,
+            content,
+            position + 1,
+            1,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// From string with even chars with only one item repeated first and second part of string,
+/// calculates priority applying pattern described in this doc: <https://adventofcode.com/2022/day/3>.
+pub fn calculate_priority_from_content(content: &str) -> u32 {
+    let mut seen = [(false, false); 53];
+
+    for (i, c) in content.chars().enumerate() {
+        if let Some(idx) = get_char_alphabetical_index(&c) {
+            // we are in first part of current string.
+            if i < (content.len() / 2) {
+                seen[idx].0 = true;
+            } else {
+                seen[idx].1 = true;
+            }
+        }
+    }
+
+    seen.iter()
+        .enumerate()
+        .fold(0u32, |mut acc, (curr_idx, curr_value)| {
+            if curr_value.0 && curr_value.1 {
+                acc += curr_idx as u32;
+            }
+            acc
+        })
+}
+
+/// From a group of strings, find the only one char that is present in all strings.
+/// Calculates priority applying pattern described in this doc: <https://adventofcode.com/2022/day/3> (second puzzle).
+pub fn calculate_priority_from_contents(contents: &[impl AsRef<str>]) -> u32 {
+    let mut seen = [0usize; 53];
+
+    for (content_index, content) in contents.iter().enumerate() {
+        for c in content.as_ref().chars() {
+            if let Some(idx) = get_char_alphabetical_index(&c) {
+                // current content index should be equal to times that a char should be seen.
+                if seen[idx] == content_index {
+                    seen[idx] += 1;
+                }
+            }
+        }
+    }
+
+    seen.iter()
+        .enumerate()
+        .fold(0u32, |mut acc, (curr_idx, curr_value)| {
+            // a char to be count has to be seen in each content.
+            if *curr_value == contents.len() {
+                acc += curr_idx as u32;
+            }
+            acc
+        })
+}
+
+/// Item with a priority, the opposite of [`get_char_alphabetical_index`].
+fn get_item_from_priority(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => Some((b'a' + (priority - 1) as u8) as char),
+        27..=52 => Some((b'A' + (priority - 27) as u8) as char),
+        _ => None,
+    }
+}
+
+/// From a char gets it's priority by applying pattern: a,..,z,A...,Z == 1,..26,27,..52 .
+pub fn get_char_alphabetical_index(c: &char) -> Option<usize> {
+    // A plain cast would truncate chars out of ascii into letters.
+    let current_char_ascii = u8::try_from(*c).ok()?;
+
+    if current_char_ascii >= RANGE_LOWER_CASE_ASCII.0
+        && current_char_ascii <= RANGE_LOWER_CASE_ASCII.1
+    {
+        // normalization.
+        let idx = current_char_ascii - RANGE_LOWER_CASE_ASCII.0 + 1;
+        return Some(idx as usize);
+    } else if current_char_ascii >= RANGE_UPPER_CASE_ASCII.0
+        && current_char_ascii <= RANGE_UPPER_CASE_ASCII.1
+    {
+        // normalization.
+        let idx = current_char_ascii - RANGE_UPPER_CASE_ASCII.0 + 27;
+        return Some(idx as usize);
+    }
+
+    None
+}
+
+/// Part A: priority of items in both compartments, part B: priority of badges of groups of `group_size` (3) elves.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+    const PARAMS: &'static [Param] = &[Param {
+        name: "group_size",
+        default: 3,
+        min: 1,
+        about: "Number of elves in a group sharing a badge, in part B.",
+    }];
+
+    type Input = Vec<Rucksack>;
+
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+        get_rucksacks(reader)
+    }
+
+    fn part_a(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        Ok(total_priority(input)?.to_string())
+    }
+
+    fn part_b(input: &Self::Input, params: &Params) -> Result<String, Error> {
+        Ok(total_priority_by_group(input, params.get("group_size") as usize).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        calculate_priority_from_content, calculate_priority_from_contents,
+        get_char_alphabetical_index, get_total_priority, get_total_priority_by_group,
+    };
+    use aoc_common::Error;
+    use rstest::rstest;
+    use std::{fs::File, io::BufReader};
+
+    #[rstest]
+    #[case('a', 1)]
+    #[case('c', 3)]
+    #[case('z', 26)]
+    #[case('A', 27)]
+    #[case('D', 30)]
+    #[case('Z', 52)]
+    fn should_get_correct_alphabet_index_for_char(#[case] c: char, #[case] expected: usize) {
+        assert_eq!(expected, get_char_alphabetical_index(&c).unwrap());
+    }
+
+    #[rstest]
+    #[case('1')]
+    #[case('Ł')]
+    #[case('\u{e2641}')]
+    fn should_not_get_alphabet_index_for_other_chars(#[case] c: char) {
+        assert_eq!(None, get_char_alphabetical_index(&c));
+    }
+
+    #[rstest]
+    #[case("vJrwpWtwJgWrhcsFMMfFFhFp", 16)]
+    #[case("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", 38)]
+    #[case("PmmdzqPrVvPwwTWBwg", 42)]
+    #[case("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn", 22)]
+    #[case("ttgJtRGJQctTZtZT", 20)]
+    #[case("CrZsJsPPZsGzwwsLwLmpwMDw", 19)]
+    fn should_find_correct_priority(#[case] content: &str, #[case] expected: u32) {
+        assert_eq!(expected, calculate_priority_from_content(content));
+    }
+
+    #[rstest]
+    #[case([String::from("vJrwpWtwJgWrhcsFMMfFFhFp"), String::from("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"), String::from("PmmdzqPrVvPwwTWBwg")], 18)]
+    #[case([String::from("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn"), String::from("ttgJtRGJQctTZtZT"), String::from("CrZsJsPPZsGzwwsLwLmpwMDw")], 52)]
+    fn should_find_correct_priority_for_groups(
+        #[case] contents: [String; 3],
+        #[case] expected: u32,
+    ) {
+        assert_eq!(expected, calculate_priority_from_contents(&contents))
+    }
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", 3, 70)]
+    fn should_calculate_correct_priority_for_file_with_group_contents(
+        #[case] path: &str,
+        #[case] chunk_size: usize,
+        #[case] expected: u32,
+    ) {
+        // Retrieve file.
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(
+            expected,
+            get_total_priority_by_group(&mut reader, chunk_size).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", 157)]
+    fn should_calculate_corret_priority_for_file(#[case] path: &str, #[case] expected: u32) {
+        // Retrieve file.
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(expected, get_total_priority(&mut reader).unwrap());
+    }
+
+    #[rstest]
+    #[case("vJrwpWtwJgWrhcsFMMfFFhFp\nPmmdz1PrVvPwwTWBwg", 2, 6, "1")]
+    #[case(
+        "vJrwpWtwJgWrhcsFMMfFFhFp\nPmmdzqPrVvPwwTWBw",
+        2,
+        1,
+        "PmmdzqPrVvPwwTWBw"
+    )]
+    fn should_point_to_invalid_rucksack(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        match get_total_priority(&mut input.as_bytes()) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(line, err.line);
+                assert_eq!(column, err.column);
+                assert_eq!(text, err.text);
+            }
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+
+    #[rstest]
+    fn should_point_to_invalid_rucksack_in_group() {
+        let input =
+            "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nab-c";
+
+        match get_total_priority_by_group(&mut input.as_bytes(), 3) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(4, err.line);
+                assert_eq!(3, err.column);
+            }
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+}
+
+/// Counting arrays checked against brute force versions based on sets.
+#[cfg(test)]
+mod differential_tests {
+    use crate::{
+        calculate_priority_from_content, calculate_priority_from_contents,
+        get_char_alphabetical_index,
+    };
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn naive_priority(c: char) -> Option<usize> {
+        ('a'..='z')
+            .chain('A'..='Z')
+            .position(|item| item == c)
+            .map(|position| position + 1)
+    }
+
+    /// Sum of priorities of items found in every content.
+    fn naive_common_priority(contents: &[&str]) -> u32 {
+        let mut common = contents
+            .first()
+            .map(|content| content.chars().collect::<HashSet<char>>())
+            .unwrap_or_default();
+
+        for content in contents.iter().skip(1) {
+            common.retain(|c| content.contains(*c));
+        }
+
+        common
+            .into_iter()
+            .filter_map(naive_priority)
+            .map(|priority| priority as u32)
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn priority_agrees_with_naive(c in any::<char>()) {
+            prop_assert_eq!(naive_priority(c), get_char_alphabetical_index(&c));
+        }
+
+        #[test]
+        fn rucksack_priority_agrees_with_naive(
+            first in "[a-zA-Z]{0,40}",
+            second in "[a-zA-Z]{0,40}",
+        ) {
+            // Compartments must have the same size.
+            let size = first.len().min(second.len());
+            let (first, second) = (&first[..size], &second[..size]);
+
+            prop_assert_eq!(
+                naive_common_priority(&[first, second]),
+                calculate_priority_from_content(&format!("{}{}", first, second))
+            );
+        }
+
+        #[test]
+        fn group_priority_agrees_with_naive(contents in prop::collection::vec("[a-zA-Z]{0,40}", 1..5)) {
+            let contents_ref = contents.iter().map(String::as_str).collect::<Vec<&str>>();
+
+            prop_assert_eq!(
+                naive_common_priority(&contents_ref),
+                calculate_priority_from_contents(&contents)
+            );
+        }
+    }
+}
@@ -0,0 +1,542 @@
+//! [Day 4: Camp Cleanup](https://adventofcode.com/2022/day/4).
+//! Input is a pair of section ranges per line: "2-8,3-7".
+
+use aoc_common::{parse::Cursor, Error, Params, ParseError, Solution};
+#[cfg(feature = "viz")]
+use aoc_viz::{Color, Draw, Frame};
+use std::{cmp::Ordering, io::BufRead};
+use tracing::{debug, trace};
+
+#[derive(Debug, PartialEq, Eq)]
+enum PointType {
+    Start,
+    End,
+}
+
+#[derive(Debug)]
+struct Point {
+    value: u8,
+    p_type: PointType,
+    index: usize,
+}
+
+/// Counts number of pairs that overlaps.
+/// Each line represent two pairs.
+/// Each line has to be in the following example format: 12-23,32-23.
+/// (2-8, 4-6) is an overlap.
+/// (2-8, 5-9) is no an overlap
+pub fn get_count_of_pair_that_overlaps(reader: &mut impl BufRead) -> Result<u32, Error> {
+    Ok(count_of_pairs_that_overlap(&get_pairs(reader)?))
+}
+
+/// Counts number of pairs that intersects.
+/// Each line represent two pairs.
+/// Each line has to be in the following example format: 12-23,32-23.
+/// (4-6, 5-8) is in intersection.
+/// (4-6, 7-8) is not an intersection.
+pub fn get_count_of_pair_that_intersects(reader: &mut impl BufRead) -> Result<u32, Error> {
+    Ok(count_of_pairs_that_intersect(&get_pairs(reader)?))
+}
+
+/// Ranges of each line.
+pub fn get_pairs(reader: &mut impl BufRead) -> Result<Vec<Vec<(u8, u8)>>, Error> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, l)| Ok(get_pairs_from_line(&l?, idx + 1)?))
+        .collect()
+}
+
+/// Number of lines where a range contains another.
+fn count_of_pairs_that_overlap(pairs: &[Vec<(u8, u8)>]) -> u32 {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(idx, pairs)| {
+            let overlap = check_if_there_is_at_least_one_overlap(pairs);
+            debug!(line = idx + 1, ?pairs, overlap, "pair");
+            overlap
+        })
+        .count() as u32
+}
+
+/// Number of lines where ranges share a section.
+fn count_of_pairs_that_intersect(pairs: &[Vec<(u8, u8)>]) -> u32 {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(idx, pairs)| {
+            let intersection = check_if_there_is_at_least_one_intersection(pairs);
+            debug!(line = idx + 1, ?pairs, intersection, "pair");
+            intersection
+        })
+        .count() as u32
+}
+
+/// Convert line into array of [(u8, u8)]: "12-23,32-23" => [(12, 23), (32, 23)].
+pub fn get_pairs_from_line(line: &str, line_number: usize) -> Result<Vec<(u8, u8)>, ParseError> {
+    let mut cursor = Cursor::new(line, line_number);
+    let pairs = cursor.separated(",", Cursor::range::<u8>)?;
+
+    if pairs.len() != 2 || !cursor.is_empty() {
+        return Err(cursor.whole_line("Expected two ranges separated by a comma."));
+    }
+
+    Ok(pairs)
+}
+
+/// Check if in an array of pairs, returns true if at least one pair overlap (contains) another.
+/// Ranges are swept by start, with the widest first on the same start: a range is contained by a previous one
+/// when it doesn't end after the farthest end seen so far.
+/// Tracking a single open range, as the intersection check does, misses cases like: [(6,17),(0,6),(6,7)].
+pub fn check_if_there_is_at_least_one_overlap(ranges: &[(u8, u8)]) -> bool {
+    let mut ranges = ranges.to_vec();
+    let mut farthest_end: Option<u8> = None;
+
+    ranges.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    trace!(?ranges, "sorted sweep ranges");
+
+    for (_, end) in ranges {
+        if farthest_end.is_some_and(|farthest_end| end <= farthest_end) {
+            return true;
+        }
+
+        farthest_end = farthest_end.max(Some(end));
+    }
+
+    false
+}
+
+/// A ""porting of sweep line algorithm"" (at least taking inspiration from it).
+/// Check if in an array of pairs, returns true if at least one pair intersect with another.
+pub fn check_if_there_is_at_least_one_intersection(ranges: &[(u8, u8)]) -> bool {
+    // Prepare input.
+    let points = create_array_of_points(ranges);
+    trace!(?points, "sorted sweep points");
+    let mut current_start_option: Option<Point> = None;
+    let mut current_end_option: Option<Point> = None;
+
+    for point in points {
+        match &current_start_option {
+            Some(current_start) => {
+                // If we found two starts with the same value there is an intersection.
+                if point.p_type == PointType::Start && current_start.value == point.value {
+                    return true;
+                }
+
+                if point.p_type == PointType::End {
+                    // if current pair ends with same index of start, may be there is not an intersection.
+                    // Intersections in edge cases such as: [(15,15),(15,32)] exist and are handled by previous check.
+                    if point.index == current_start.index {
+                        current_start_option = None;
+                        current_end_option = Some(point);
+                        continue;
+                    }
+                }
+
+                // If it's a start or end different from current index there is an intersection.
+                return true;
+            }
+            None => {
+                if point.p_type == PointType::Start {
+                    current_start_option = Some(point);
+                    continue;
+                }
+
+                // If we found two ends with the same value there is an intersection.
+                if let Some(current_end) = &current_end_option {
+                    if current_end.value == point.value {
+                        return true;
+                    }
+                };
+
+                current_end_option = Some(point);
+            }
+        }
+    }
+
+    false
+}
+
+/// From an array of [(u8, u8)] creates an array of points sorted applying sweep line sort rules.
+fn create_array_of_points(ranges: &[(u8, u8)]) -> Vec<Point> {
+    let mut points =
+        ranges
+            .iter()
+            .enumerate()
+            .fold(Vec::<Point>::new(), |mut acc, (curr_idx, curr_value)| {
+                acc.push(Point {
+                    value: curr_value.0,
+                    p_type: PointType::Start,
+                    index: curr_idx,
+                });
+                acc.push(Point {
+                    value: curr_value.1,
+                    p_type: PointType::End,
+                    index: curr_idx,
+                });
+
+                acc
+            });
+
+    sort_input_for_compare_pairs(&mut points);
+
+    points
+}
+
+/// Given an array of points sort it applying sweep line rules.
+fn sort_input_for_compare_pairs(points: &mut [Point]) {
+    points.sort_unstable_by(|a, b| {
+        // points have same value, the point that is a Start comes first.
+        if a.value == b.value {
+            if a.p_type == PointType::Start {
+                return Ordering::Less;
+            }
+
+            return Ordering::Greater;
+        } else if a.value < b.value {
+            return Ordering::Less;
+        }
+
+        Ordering::Greater
+    });
+}
+
+/// Colors of sections assigned to a single elf, a color per elf of the pair.
+#[cfg(feature = "viz")]
+const ELF_COLORS: [Color; 2] = [Color::Green, Color::Blue];
+
+/// Section ranges of a pair drawn as in puzzle description: a row per elf, ".234....." for 2-4.
+/// Sections assigned to more than one elf are red.
+#[cfg(feature = "viz")]
+pub struct Assignments<'a> {
+    pub ranges: &'a [(u8, u8)],
+    /// Number of sections drawn, so that every frame has the same width.
+    pub sections: u8,
+}
+
+#[cfg(feature = "viz")]
+impl Draw for Assignments<'_> {
+    fn draw(&self) -> Frame {
+        let mut frame = Frame::new();
+
+        for (row, (start, end)) in self.ranges.iter().enumerate() {
+            for section in 1..=self.sections.max(*end) {
+                let elves = self
+                    .ranges
+                    .iter()
+                    .filter(|(s, e)| (*s..=*e).contains(&section))
+                    .count();
+                // Last digit of section, as in puzzle description.
+                let digit = char::from_digit(section as u32 % 10, 10).unwrap_or('#');
+                let (char, color) = match ((*start..=*end).contains(&section), elves) {
+                    (true, 1) => (digit, ELF_COLORS[row % ELF_COLORS.len()]),
+                    (true, _) => (digit, Color::Red),
+                    (false, _) => ('.', Color::Gray),
+                };
+
+                frame.write(row, section as usize - 1, &char.to_string(), color);
+            }
+        }
+
+        frame
+    }
+}
+
+/// Frames of each pair of section ranges, captioned with whether a range contains the other or they intersect.
+#[cfg(feature = "viz")]
+pub fn visualize(input: &str) -> Result<Vec<Frame>, Error> {
+    let pairs = input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| get_pairs_from_line(line, idx + 1).map(|pairs| (line, pairs)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let sections = pairs
+        .iter()
+        .flat_map(|(_, ranges)| ranges.iter().map(|range| range.1))
+        .max()
+        .unwrap_or(0);
+
+    Ok(pairs
+        .iter()
+        .map(|(line, ranges)| {
+            let (verdict, color) = match (
+                check_if_there_is_at_least_one_overlap(ranges),
+                check_if_there_is_at_least_one_intersection(ranges),
+            ) {
+                (true, _) => ("contained", Color::Red),
+                (false, true) => ("intersect", Color::Yellow),
+                (false, false) => ("apart", Color::Gray),
+            };
+
+            Assignments { ranges, sections }
+                .draw()
+                .with_caption(&format!("{}: {}", line, verdict), color)
+        })
+        .collect())
+}
+
+/// Part A: pairs where a range contains the other, part B: pairs where ranges intersect.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<Vec<(u8, u8)>>;
+
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+        get_pairs(reader)
+    }
+
+    fn part_a(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        Ok(count_of_pairs_that_overlap(input).to_string())
+    }
+
+    fn part_b(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        Ok(count_of_pairs_that_intersect(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        check_if_there_is_at_least_one_intersection, check_if_there_is_at_least_one_overlap,
+        get_count_of_pair_that_overlaps, get_pairs_from_line, sort_input_for_compare_pairs, Point,
+        PointType,
+    };
+    use rstest::rstest;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    #[rstest]
+    #[case(&[(11,11), (11,15)], true)]
+    #[case(&[(15u8,51u8), (15,15)], true)]
+    #[case(&[(2u8,4u8), (6,8)], false)]
+    #[case(&[(2u8,8u8), (3,7), (4,5)], true)]
+    #[case(&[(2u8,8u8), (3,7)], true)]
+    #[case(&[(2u8,8u8), (10,12)], false)]
+    #[case(&[(0u8,2u8), (1,2)], true)]
+    #[case(&[(0u8,2u8), (5,6)], false)]
+    #[case(&[(0u8,2u8), (2,6)], false)]
+    #[case(&[(6u8,6u8), (4,6)], true)]
+    #[case(&[(3u8,5u8), (3,5)], true)]
+    #[case(&[(3u8,5u8), (2,6)], true)]
+    #[case(&[(5u8,7u8), (7,9)], false)]
+    #[case(&[(3u8,5u8), (1,2), (10, 20), (30, 40), (50, 60)], false)]
+    #[case(&[(3u8,5u8), (1,2), (10, 20), (30, 40), (50, 60), (3, 5)], true)]
+    #[case(&[(6u8,17u8), (0,6), (6,7)], true)]
+    fn should_find_overlap(#[case] input: &[(u8, u8)], #[case] expected: bool) {
+        assert_eq!(expected, check_if_there_is_at_least_one_overlap(input))
+    }
+
+    #[rstest]
+    #[case(&[(2,4),(6,8),(2,3),(4,5),(5,7), (7,9), (2,8), (3,7), (6,6), (4,6), (2,6), (4,8)], 4)]
+    fn should_find_count_of_overlaps(#[case] input: &[(u8, u8)], #[case] expected: u32) {
+        let sum = input.chunks(2).fold(0u32, |mut acc, curr| {
+            if check_if_there_is_at_least_one_intersection(curr) {
+                acc += 1;
+            }
+
+            acc
+        });
+
+        assert_eq!(expected, sum)
+    }
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", 2)]
+    fn should_get_count_of_pair_overlaps(#[case] path: &str, #[case] expected: u32) {
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(
+            expected,
+            get_count_of_pair_that_overlaps(&mut reader).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case("2-4,6-8", &[(2u8, 4u8), (6, 8)])]
+    #[case("15-15,15-32", &[(15u8, 15u8), (15, 32)])]
+    fn should_get_pairs_from_line(#[case] line: &str, #[case] expected: &[(u8, u8)]) {
+        assert_eq!(expected.to_vec(), get_pairs_from_line(line, 1).unwrap());
+    }
+
+    #[rstest]
+    #[case("2-4,6-x", 7, "x")]
+    #[case("2-4,6", 5, "6")]
+    #[case("2-4,6-8,1-2", 1, "2-4,6-8,1-2")]
+    #[case("2-4", 1, "2-4")]
+    #[case("2-256,6-8", 3, "256")]
+    #[case("", 1, "")]
+    fn should_point_to_invalid_section(
+        #[case] line: &str,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        let err = get_pairs_from_line(line, 3).unwrap_err();
+
+        assert_eq!(3, err.line);
+        assert_eq!(column, err.column);
+        assert_eq!(text, err.text);
+    }
+
+    #[rstest]
+    fn should_sort() {
+        let mut arr = [
+            Point {
+                index: 1,
+                value: 5,
+                p_type: PointType::Start,
+            },
+            Point {
+                index: 1,
+                value: 8,
+                p_type: PointType::End,
+            },
+            Point {
+                index: 2,
+                value: 3,
+                p_type: PointType::Start,
+            },
+            Point {
+                index: 2,
+                value: 7,
+                p_type: PointType::End,
+            },
+            Point {
+                index: 3,
+                value: 4,
+                p_type: PointType::Start,
+            },
+            Point {
+                index: 3,
+                value: 5,
+                p_type: PointType::End,
+            },
+        ];
+
+        sort_input_for_compare_pairs(&mut arr);
+
+        let expected = [
+            Point {
+                index: 2,
+                value: 3,
+                p_type: PointType::Start,
+            },
+            Point {
+                index: 3,
+                value: 4,
+                p_type: PointType::Start,
+            },
+            Point {
+                index: 1,
+                value: 5,
+                p_type: PointType::Start,
+            },
+            Point {
+                index: 3,
+                value: 5,
+                p_type: PointType::End,
+            },
+            Point {
+                index: 2,
+                value: 7,
+                p_type: PointType::End,
+            },
+            Point {
+                index: 1,
+                value: 8,
+                p_type: PointType::End,
+            },
+        ];
+
+        for (idx, curr) in arr.iter().enumerate() {
+            let p = &expected[idx];
+
+            assert_eq!(p.index, curr.index);
+            assert!(p.p_type == curr.p_type);
+            assert_eq!(p.value, curr.value);
+        }
+    }
+}
+
+/// Frames drawn by [`visualize`].
+#[cfg(all(test, feature = "viz"))]
+mod viz_tests {
+    use crate::visualize;
+    use aoc_viz::Color;
+
+    #[test]
+    fn should_draw_a_frame_per_pair() {
+        let frames = visualize("2-4,6-8\n2-8,3-7\n5-7,7-9").unwrap();
+
+        assert_eq!(3, frames.len());
+        assert_eq!("2-4,6-8: apart\n.234.....\n.....678.", frames[0].text());
+        assert_eq!("2-8,3-7: contained\n.2345678.\n..34567..", frames[1].text());
+        assert_eq!("5-7,7-9: intersect\n....567..\n......789", frames[2].text());
+        // Section 7 is assigned to both elves.
+        assert_eq!(Color::Red, frames[2].rows()[1][6].color);
+        assert_ne!(Color::Red, frames[2].rows()[1][5].color);
+    }
+}
+
+/// Sweep line functions checked against brute force versions comparing every couple of ranges.
+#[cfg(test)]
+mod differential_tests {
+    use crate::{
+        check_if_there_is_at_least_one_intersection, check_if_there_is_at_least_one_overlap,
+    };
+    use proptest::prelude::*;
+
+    /// One range contains the other.
+    fn naive_overlap(a: (u8, u8), b: (u8, u8)) -> bool {
+        (a.0 <= b.0 && b.1 <= a.1) || (b.0 <= a.0 && a.1 <= b.1)
+    }
+
+    /// Ranges share at least one section.
+    fn naive_intersection(a: (u8, u8), b: (u8, u8)) -> bool {
+        a.0.max(b.0) <= a.1.min(b.1)
+    }
+
+    fn naive_any(ranges: &[(u8, u8)], check: fn((u8, u8), (u8, u8)) -> bool) -> bool {
+        (0..ranges.len()).any(|i| (i + 1..ranges.len()).any(|j| check(ranges[i], ranges[j])))
+    }
+
+    /// A valid range: start <= end, small sections make collisions likely.
+    fn range() -> impl Strategy<Value = (u8, u8)> {
+        (0u8..20, 0u8..20).prop_map(|(a, b)| (a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn overlap_of_pair_agrees_with_naive(a in range(), b in range()) {
+            prop_assert_eq!(naive_overlap(a, b), check_if_there_is_at_least_one_overlap(&[a, b]));
+        }
+
+        #[test]
+        fn intersection_of_pair_agrees_with_naive(a in range(), b in range()) {
+            prop_assert_eq!(
+                naive_intersection(a, b),
+                check_if_there_is_at_least_one_intersection(&[a, b])
+            );
+        }
+
+        #[test]
+        fn overlap_of_ranges_agrees_with_naive(ranges in prop::collection::vec(range(), 0..8)) {
+            prop_assert_eq!(
+                naive_any(&ranges, naive_overlap),
+                check_if_there_is_at_least_one_overlap(&ranges)
+            );
+        }
+
+        #[test]
+        fn intersection_of_ranges_agrees_with_naive(ranges in prop::collection::vec(range(), 0..8)) {
+            prop_assert_eq!(
+                naive_any(&ranges, naive_intersection),
+                check_if_there_is_at_least_one_intersection(&ranges)
+            );
+        }
+    }
+}
@@ -0,0 +1,661 @@
+//! [Day 5: Supply Stacks](https://adventofcode.com/2022/day/5).
+//! Input is a drawing of crate stacks, an empty line and a move per line: "move 1 from 2 to 1".
+
+use aoc_common::{
+    parse::{Cursor, Span},
+    Error, Params, ParseError, Solution,
+};
+#[cfg(feature = "viz")]
+use aoc_viz::{Color, Draw, Frame};
+use std::{fmt, io::BufRead};
+use tracing::debug;
+
+pub use crate::giant_cargo_crane::{CrateMoverModel, Stacks};
+
+/// A move of crates between stacks numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: u8,
+    pub from: u8,
+    pub to: u8,
+}
+
+/// As in puzzle input: "move 1 from 2 to 3".
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+/// Stacks as drawn in input, with moves to apply to them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    pub stacks: Stacks<char>,
+    pub moves: Vec<Move>,
+}
+
+impl Procedure {
+    /// Top of each stack after every move of a crane of the given model.
+    pub fn get_top_of_each_stack_after_moves(
+        &self,
+        crate_mover_model: CrateMoverModel,
+    ) -> Result<String, Error> {
+        let mut stacks = self.stacks.with_crate_mover_model(crate_mover_model);
+
+        for move_ in &self.moves {
+            make_move(&mut stacks, move_)?;
+        }
+
+        Ok(stacks.get_top_of_each_stack().into_iter().collect())
+    }
+}
+
+/// Read stacks then moves.
+pub fn get_procedure(
+    reader: &mut impl BufRead,
+    crate_mover_model: CrateMoverModel,
+) -> Result<Procedure, Error> {
+    let stacks = Stacks::<char>::generate(reader, crate_mover_model)?;
+    let moves = get_moves(reader, &stacks)?;
+
+    Ok(Procedure { stacks, moves })
+}
+
+/// From a line like "move 14 from 3 to 4" get a move, with where its stacks are in line so that errors can point to them.
+fn get_move(cursor: &mut Cursor) -> Result<(Move, Span, Span), ParseError> {
+    cursor.tag("move ")?;
+    let count = cursor.integer()?;
+    cursor.tag(" from ")?;
+    let (from, from_span) = cursor.spanned(Cursor::integer)?;
+    cursor.tag(" to ")?;
+    let (to, to_span) = cursor.spanned(Cursor::integer)?;
+    cursor.end()?;
+
+    Ok((Move { count, from, to }, from_span, to_span))
+}
+
+/// Read moves between existing stacks, one per line, empty lines are skipped.
+/// Reader has to be the same used to generate stacks, so that errors point to the right line.
+pub fn get_moves(reader: &mut impl BufRead, stacks: &Stacks<char>) -> Result<Vec<Move>, Error> {
+    let mut moves = Vec::new();
+
+    for (idx, l) in reader.lines().enumerate() {
+        let line = l?;
+        let line_number = stacks.lines_read() + idx + 1;
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut cursor = Cursor::new(&line, line_number);
+        let (move_, from_span, to_span) = get_move(&mut cursor)
+            .map_err(|err| err.with_message("Expected a move like: move 1 from 2 to 3."))?;
+
+        for (stack, span) in [(move_.from, from_span), (move_.to, to_span)] {
+            if !stacks.has_stack(stack) {
+                return Err(cursor
+                    .error_at(span, format!("Stack {} doesn't exist.", stack))
+                    .into());
+            }
+        }
+
+        moves.push(move_);
+    }
+
+    Ok(moves)
+}
+
+/// Read moves from a file and apply them to Stacks.
+/// Reader has to be the same used to generate stacks, so that errors point to the right line.
+pub fn make_moves(reader: &mut impl BufRead, stacks: &mut Stacks<char>) -> Result<(), Error> {
+    for move_ in get_moves(reader, stacks)? {
+        make_move(stacks, &move_)?;
+    }
+
+    Ok(())
+}
+
+/// Apply a move to stacks.
+fn make_move(stacks: &mut Stacks<char>, move_: &Move) -> Result<(), Error> {
+    stacks
+        .move_n(move_.from, move_.to, move_.count)
+        .map_err(Error::NoAnswer)?;
+    debug!(
+        %move_,
+        top = %stacks.get_top_of_each_stack().into_iter().collect::<String>(),
+        "move"
+    );
+
+    Ok(())
+}
+
+pub mod giant_cargo_crane {
+    use aoc_common::{parse::Cursor, Error, ParseError};
+    #[cfg(feature = "viz")]
+    use aoc_viz::{Color, Draw, Frame};
+    use std::{collections::HashMap, io::BufRead};
+
+    /// Stacks are numbered by a u8.
+    const MAX_STACKS_MESSAGE: &str = "Expected at most 255 stacks.";
+
+    /// How a crane moves many crates at once: M9000 one at a time, M9001 all together keeping their order.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CrateMoverModel {
+        M9000,
+        M9001,
+    }
+
+    /// Stacks of crates numbered from 1, with the crane that moves them.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Stacks<T> {
+        stacks: HashMap<u8, Vec<T>>,
+        crate_mover_model: CrateMoverModel,
+        // Lines of input read to generate stacks.
+        lines_read: usize,
+    }
+
+    impl Stacks<char> {
+        /// Create stacks from a reader.
+        /// Reads until the line with stack numbers (eg: " 1   2   3 ") included.
+        pub fn generate(
+            reader: &mut impl BufRead,
+            crate_mover_model: CrateMoverModel,
+        ) -> Result<Self, Error> {
+            let mut stacks_map: HashMap<u8, Vec<char>> = HashMap::new();
+            let mut lines_read = 0usize;
+
+            for l in reader.lines() {
+                let line = l?;
+                lines_read += 1;
+
+                // We end with informations for stacks.
+                if line.starts_with(" 1") {
+                    let stacks_count = get_stacks_count(&line, lines_read)?;
+
+                    if let Some(stack) = stacks_map.keys().find(|idx| **idx > stacks_count) {
+                        return Err(ParseError::whole_line(
+                            format!("Crates are drawn over stack {} that is missing.", stack),
+                            lines_read,
+                            &line,
+                        )
+                        .into());
+                    }
+
+                    // Stacks without crates are still stacks.
+                    for idx in 1..=stacks_count {
+                        stacks_map.entry(idx).or_default();
+                    }
+
+                    for (_, stack) in stacks_map.iter_mut() {
+                        stack.reverse();
+                    }
+
+                    return Ok(Self {
+                        stacks: stacks_map,
+                        crate_mover_model,
+                        lines_read,
+                    });
+                }
+
+                if line.trim().is_empty() {
+                    return Err(ParseError::whole_line(
+                        "Expected a line of crates or stack numbers.",
+                        lines_read,
+                        &line,
+                    )
+                    .into());
+                }
+
+                for (idx, crate_) in get_crates_from_line(&line, lines_read)?
+                    .into_iter()
+                    .enumerate()
+                {
+                    if let Some(crate_) = crate_ {
+                        // Crates are 3 chars wide, separated by a space.
+                        let stack = u8::try_from(idx + 1).map_err(|_| {
+                            ParseError::new(MAX_STACKS_MESSAGE, lines_read, &line, idx * 4 + 1, 3)
+                        })?;
+
+                        stacks_map.entry(stack).or_default().push(crate_);
+                    }
+                }
+            }
+
+            Err(ParseError::new("Missing stack numbers line.", lines_read + 1, "", 1, 0).into())
+        }
+
+        /// Same stacks moved by a crane of another model.
+        pub fn with_crate_mover_model(&self, crate_mover_model: CrateMoverModel) -> Self {
+            Self {
+                crate_mover_model,
+                ..self.clone()
+            }
+        }
+
+        /// Number of input lines consumed by [`Stacks::generate`].
+        pub fn lines_read(&self) -> usize {
+            self.lines_read
+        }
+
+        /// Whether stack numbered `idx` exists.
+        pub fn has_stack(&self, idx: u8) -> bool {
+            self.stacks.contains_key(&idx)
+        }
+
+        /// Move from one stack to another the n elements on top.
+        /// May be we can make this code better using Interior mutability.
+        pub fn move_n(&mut self, from: u8, to: u8, n: u8) -> Result<(), String> {
+            if !self.stacks.contains_key(&to) {
+                return Err(format!("Stack {} doesn't exist.", to));
+            }
+
+            let first_stack = self
+                .stacks
+                .get_mut(&from)
+                .ok_or_else(|| format!("Stack {} doesn't exist.", from))?;
+            let mut values = Vec::<char>::new();
+
+            for _ in 0..n {
+                if let Some(v) = first_stack.pop() {
+                    if self.crate_mover_model == CrateMoverModel::M9000 {
+                        values.push(v);
+                    } else {
+                        values.insert(0, v);
+                    }
+                }
+            }
+
+            let to_stack = self.stacks.get_mut(&to).unwrap();
+
+            for i in values {
+                to_stack.push(i);
+            }
+
+            Ok(())
+        }
+
+        /// Crates of each stack from bottom to top, in stack order.
+        pub fn crates(&self) -> Vec<Vec<char>> {
+            (1..=self.stacks.len() as u8)
+                .map(|idx| self.stacks.get(&idx).cloned().unwrap_or_default())
+                .collect()
+        }
+
+        /// Reference top element of each stack into a vec.
+        pub fn get_top_of_each_stack(&self) -> Vec<&char> {
+            self.stacks
+                .iter()
+                .fold(vec![&'-'; self.stacks.len()], |mut acc, (idx, stack)| {
+                    if let Some(c) = stack.last() {
+                        acc[(*idx - 1) as usize] = c;
+                    }
+                    acc
+                })
+        }
+    }
+
+    #[cfg(feature = "viz")]
+    impl Draw for Stacks<char> {
+        /// Stacks as in puzzle input: crates like "[A]" on top of each other, stack numbers below.
+        /// Each letter keeps its color while moving.
+        fn draw(&self) -> Frame {
+            let height = self.stacks.values().map(Vec::len).max().unwrap_or(0);
+            let mut frame = Frame::new();
+
+            for idx in 1..=self.stacks.len() as u8 {
+                let column = (idx as usize - 1) * 4;
+
+                for (level, crate_) in self.stacks[&idx].iter().enumerate() {
+                    frame.write(
+                        height - 1 - level,
+                        column,
+                        &format!("[{}]", crate_),
+                        Color::pick(*crate_ as usize),
+                    );
+                }
+                frame.write(height, column + 1, &idx.to_string(), Color::Gray);
+            }
+
+            frame
+        }
+    }
+
+    /// From stack numbers line " 1   2   3 " get number of stacks, they have to be consecutive starting from 1.
+    fn get_stacks_count(line: &str, line_number: usize) -> Result<u8, ParseError> {
+        let mut cursor = Cursor::new(line, line_number);
+        let mut count = 0u8;
+
+        loop {
+            cursor.take_while(|c| c == ' ');
+
+            if cursor.is_empty() {
+                return Ok(count);
+            }
+
+            let (number, span) = cursor.spanned(Cursor::word)?;
+
+            let expected = count
+                .checked_add(1)
+                .ok_or_else(|| cursor.error_at(span, MAX_STACKS_MESSAGE))?;
+
+            if number.parse::<u8>().ok() != Some(expected) {
+                return Err(cursor.error_at(span, format!("Expected stack number {}.", expected)));
+            }
+            count = expected;
+        }
+    }
+
+    /// Crates of a drawing line (check file and <https://adventofcode.com/2022/day/5> puzzle input),
+    /// None for stacks without a crate there: "    [A] [B]" => [None, Some('A'), Some('B')].
+    fn get_crates_from_line(
+        line: &str,
+        line_number: usize,
+    ) -> Result<Vec<Option<char>>, ParseError> {
+        let mut cursor = Cursor::new(line, line_number);
+        let crates = cursor.separated(" ", get_crate)?;
+
+        cursor
+            .end()
+            .map_err(|err| err.with_message("Expected crates like: [A] separated by a space."))?;
+
+        Ok(crates)
+    }
+
+    /// A crate like "[A]", or 3 spaces when there is no crate.
+    fn get_crate(cursor: &mut Cursor) -> Result<Option<char>, ParseError> {
+        if cursor.tag("   ").is_ok() {
+            return Ok(None);
+        }
+
+        cursor
+            .tag("[")
+            .map_err(|err| err.with_message("Expected a crate like: [A], or 3 spaces."))?;
+        let crate_ = cursor.char()?;
+        cursor.tag("]")?;
+
+        Ok(Some(crate_))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{get_crates_from_line, get_stacks_count};
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(" 1   2   3 ", 3)]
+        #[case(" 1 ", 1)]
+        fn should_get_stacks_count(#[case] input: &str, #[case] expected: u8) {
+            assert_eq!(expected, get_stacks_count(input, 1).unwrap());
+        }
+
+        #[rstest]
+        #[case(" 1   3 ", 6, "3")]
+        #[case(" 1   x ", 6, "x")]
+        #[case(" 1   1 ", 6, "1")]
+        fn should_not_get_stacks_count(
+            #[case] input: &str,
+            #[case] column: usize,
+            #[case] text: &str,
+        ) {
+            let err = get_stacks_count(input, 1).unwrap_err();
+
+            assert_eq!(column, err.column);
+            assert_eq!(text, err.text);
+        }
+
+        #[rstest]
+        #[case(256, "", "256")]
+        #[case(255, " x", "x")]
+        fn should_not_count_more_than_255_stacks(
+            #[case] stacks: usize,
+            #[case] after: &str,
+            #[case] text: &str,
+        ) {
+            let line = (1..=stacks)
+                .map(|idx| format!(" {} ", idx))
+                .collect::<Vec<_>>()
+                .join(" ")
+                + after;
+
+            let err = get_stacks_count(&line, 1).unwrap_err();
+
+            assert_eq!(text, err.text);
+        }
+
+        #[rstest]
+        #[case("    [B]             [B] [S]        ", "-B---BS--")]
+        #[case("    [M]             [P] [L] [B] [J]", "-M---PLBJ")]
+        #[case("    [T] [R] [Z]     [H] [H] [G] [C]", "-TRZ-HHGC")]
+        #[case("[B] [L] [Q] [W] [S] [L] [J] [W] [Z]", "BLQWSLJWZ")]
+        fn should_get_crates_from_line(#[case] input: &str, #[case] expected: &str) {
+            let crates = get_crates_from_line(input, 1).unwrap();
+
+            assert_eq!(
+                expected,
+                crates
+                    .into_iter()
+                    .map(|c| c.unwrap_or('-'))
+                    .collect::<String>()
+            );
+        }
+
+        #[rstest]
+        #[case("[A] [B", 7, "")]
+        #[case("[A]  [B]", 5, " ")]
+        #[case("[A] B", 5, "B")]
+        fn should_point_to_invalid_crate(
+            #[case] input: &str,
+            #[case] column: usize,
+            #[case] text: &str,
+        ) {
+            let err = get_crates_from_line(input, 1).unwrap_err();
+
+            assert_eq!(column, err.column);
+            assert_eq!(text, err.text);
+        }
+    }
+}
+
+/// Generate stacks using given crate mover model, apply moves and get top of each stack.
+pub fn get_top_of_each_stack_after_moves(
+    input: &str,
+    crate_mover_model: CrateMoverModel,
+) -> Result<String, Error> {
+    get_procedure(&mut input.as_bytes(), crate_mover_model)?
+        .get_top_of_each_stack_after_moves(crate_mover_model)
+}
+
+/// Frames of stacks before moves, then after each move with the move as caption.
+#[cfg(feature = "viz")]
+pub fn visualize(input: &str, crate_mover_model: CrateMoverModel) -> Result<Vec<Frame>, Error> {
+    let Procedure { mut stacks, moves } = get_procedure(&mut input.as_bytes(), crate_mover_model)?;
+    let mut frames = vec![stacks.draw().with_caption("start", Color::Yellow)];
+
+    for move_ in moves {
+        make_move(&mut stacks, &move_)?;
+        frames.push(
+            stacks
+                .draw()
+                .with_caption(&move_.to_string(), Color::Yellow),
+        );
+    }
+
+    Ok(frames)
+}
+
+/// Part A: top crates after moves of a CrateMover 9000, part B: after moves of a CrateMover 9001.
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = Procedure;
+
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+        // Each part picks its own model.
+        get_procedure(reader, CrateMoverModel::M9000)
+    }
+
+    fn part_a(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        input.get_top_of_each_stack_after_moves(CrateMoverModel::M9000)
+    }
+
+    fn part_b(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        input.get_top_of_each_stack_after_moves(CrateMoverModel::M9001)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::{fs::File, io::BufReader};
+
+    use crate::{make_moves, CrateMoverModel, Stacks};
+    use aoc_common::{
+        cycle::{self, Memo},
+        Error,
+    };
+
+    #[rstest]
+    #[case("./src/test_inputs/input_test_1.txt", CrateMoverModel::M9000, &[&'C', &'M', &'Z'])]
+    #[case("./src/test_inputs/input_test_1.txt", CrateMoverModel::M9001, &[&'M', &'C', &'D'])]
+    fn should_make_moves(
+        #[case] input: &str,
+        #[case] crate_cover_model: CrateMoverModel,
+        #[case] expected: &[&char],
+    ) {
+        // Retrieve file.
+        let file = File::open(input).unwrap();
+        let mut reader = BufReader::new(file);
+        let mut stacks = Stacks::<char>::generate(&mut reader, crate_cover_model).unwrap();
+
+        make_moves(&mut reader, &mut stacks).unwrap();
+
+        assert_eq!(expected.to_vec(), stacks.get_top_of_each_stack())
+    }
+
+    #[rstest]
+    #[case("    [D]\n[N] [C]    \n\n 1   2 \n", 3, "")]
+    #[case("    [D]    \n[N] [C] [E]\n 1   2 \n", 3, " 1   2 ")]
+    #[case(
+        "    [D]\n[N] [C]\n 1   2 \n\nmove 1 from 2 to 1\nmove 1 from 3 to 1",
+        6,
+        "3"
+    )]
+    #[case(
+        "    [D]\n[N] [C]\n 1   2 \n\nmove 1 from 2 to 1\nmove one from 1 to 2",
+        6,
+        "one"
+    )]
+    #[case("    [D]\n[N] [C]\n 1   2 \n\nshift 1 from 1 to 2", 5, "shift")]
+    #[case("    [D]\n[N] [C]", 3, "")]
+    fn should_point_to_invalid_line(#[case] input: &str, #[case] line: usize, #[case] text: &str) {
+        let mut reader = input.as_bytes();
+        let result = Stacks::<char>::generate(&mut reader, CrateMoverModel::M9000)
+            .and_then(|mut stacks| make_moves(&mut reader, &mut stacks));
+
+        match result {
+            Err(Error::Parse(err)) => {
+                assert_eq!(line, err.line);
+                assert_eq!(text, err.text);
+            }
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+
+    #[rstest]
+    fn should_reject_more_than_255_stacks() {
+        let input = format!("{}\n 1 \n", vec!["[A]"; 256].join(" "));
+
+        match Stacks::<char>::generate(&mut input.as_bytes(), CrateMoverModel::M9000) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(1, err.line);
+                assert_eq!(255 * 4 + 1, err.column);
+                assert_eq!("[A]", err.text);
+            }
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+
+    #[rstest]
+    fn should_keep_empty_stacks() {
+        let mut reader = "[A]        \n 1   2   3 \n\nmove 1 from 1 to 3".as_bytes();
+        let mut stacks = Stacks::<char>::generate(&mut reader, CrateMoverModel::M9000).unwrap();
+
+        make_moves(&mut reader, &mut stacks).unwrap();
+
+        assert_eq!(vec![&'-', &'-', &'A'], stacks.get_top_of_each_stack())
+    }
+
+    /// Stacks after every move of `moves`.
+    fn round(stacks: &Stacks<char>, moves: &str) -> Stacks<char> {
+        let mut next = stacks.clone();
+        make_moves(&mut moves.as_bytes(), &mut next).unwrap();
+        next
+    }
+
+    #[rstest]
+    #[case(CrateMoverModel::M9000)]
+    #[case(CrateMoverModel::M9001)]
+    fn should_skip_repeated_rounds_of_moves(#[case] crate_mover_model: CrateMoverModel) {
+        let input = std::fs::read_to_string("./src/test_inputs/input_test_1.txt").unwrap();
+        let mut reader = input.as_bytes();
+        let stacks = Stacks::<char>::generate(&mut reader, crate_mover_model).unwrap();
+        let moves = std::str::from_utf8(reader).unwrap();
+
+        let history = cycle::run(
+            stacks.clone(),
+            usize::MAX,
+            |stacks| round(stacks, moves),
+            Stacks::crates,
+        );
+        let found = history.cycle.unwrap();
+
+        let mut memo = Memo::new();
+        let mut current = stacks;
+        for n in 0..3 * (found.start + found.length) {
+            assert_eq!(current.crates(), history.nth(n).unwrap().crates());
+            current = memo.get(current.crates(), |_| round(&current, moves));
+        }
+        assert_eq!(found.start + found.length, memo.len());
+        assert!(memo.hits > 0);
+
+        let billion = history.nth(1_000_000_000).unwrap();
+        assert_eq!(
+            history.states[found.start + (1_000_000_000 - found.start) % found.length].crates(),
+            billion.crates()
+        );
+    }
+}
+
+/// Frames drawn by [`visualize`].
+#[cfg(all(test, feature = "viz"))]
+mod viz_tests {
+    use crate::{visualize, CrateMoverModel};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        CrateMoverModel::M9000,
+        "move 3 from 1 to 3\n        [Z]\n        [N]\n    [C] [D]\n    [M] [P]\n 1   2   3"
+    )]
+    #[case(
+        CrateMoverModel::M9001,
+        "move 3 from 1 to 3\n        [D]\n        [N]\n    [C] [Z]\n    [M] [P]\n 1   2   3"
+    )]
+    fn should_draw_a_frame_per_move(
+        #[case] crate_mover_model: CrateMoverModel,
+        #[case] second_move: &str,
+    ) {
+        let input = std::fs::read_to_string("./src/test_inputs/input_test_1.txt").unwrap();
+        let frames = visualize(&input, crate_mover_model).unwrap();
+
+        assert_eq!(5, frames.len());
+        assert_eq!(
+            "start\n    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3",
+            frames[0].text()
+        );
+        assert_eq!(second_move, frames[2].text());
+    }
+}
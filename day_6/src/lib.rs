@@ -0,0 +1,190 @@
+//! [Day 6: Tuning Trouble](https://adventofcode.com/2022/day/6).
+//! Input is a signal: a single line of chars a..z.
+
+use aoc_common::{Error, Params, ParseError, Solution};
+use std::{collections::HashSet, io::BufRead};
+use tracing::trace;
+
+/// Find first sequence of N unique chars and returns index of last char of sequence in original input.
+pub fn find_marker(input: &str, n: usize) -> Option<usize> {
+    // Windows are made of chars, slicing input by bytes would split multi byte chars.
+    let chars = input.chars().collect::<Vec<char>>();
+
+    // There is no room for a marker.
+    if n == 0 || chars.len() < n {
+        return None;
+    }
+
+    for i in 0..chars.len() - (n - 1) {
+        let set: &HashSet<&char> = &chars[i..i + n].iter().collect();
+        trace!(
+            start = i,
+            window = %chars[i..i + n].iter().collect::<String>(),
+            unique = set.len(),
+            "window"
+        );
+
+        if set.len() == n {
+            return Some(i + n);
+        }
+    }
+
+    None
+}
+
+/// Part A: end of first start-of-packet marker (4 chars), part B: end of first start-of-message marker (14 chars).
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+    /// Binary finds a marker of any length instead of solving a part.
+    const OPTION: &'static str = "marker length, usize";
+
+    type Input = String;
+
+    /// Signal is a single line made of chars a..z.
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+        let content = std::io::read_to_string(reader)?;
+        let signal = content.trim_end();
+
+        for (idx, line) in signal.lines().enumerate() {
+            if idx > 0 {
+                return Err(ParseError::whole_line(
+                    "Signal has to be on a single line.",
+                    idx + 1,
+                    line,
+                )
+                .into());
+            }
+
+            if let Some(position) = line.chars().position(|c| !c.is_ascii_lowercase()) {
+                return Err(ParseError::new(
+                    "Only chars: a..z are allowed.",
+                    idx + 1,
+                    line,
+                    position + 1,
+                    1,
+                )
+                .into());
+            }
+        }
+
+        Ok(signal.to_string())
+    }
+
+    fn part_a(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        find_marker(input, 4)
+            .map(|idx| idx.to_string())
+            .ok_or_else(|| Error::NoAnswer(String::from("Unable to find marker.")))
+    }
+
+    fn part_b(input: &Self::Input, _: &Params) -> Result<String, Error> {
+        find_marker(input, 14)
+            .map(|idx| idx.to_string())
+            .ok_or_else(|| Error::NoAnswer(String::from("Unable to find marker.")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_marker, Puzzle};
+    use aoc_common::{Error, Part, Solution};
+    use rstest::rstest;
+    use std::{fs::File, io::BufReader};
+
+    #[rstest]
+    #[case("bvwbjplbgvbhsrlpgdmjqwftvncz", 4, 5)]
+    #[case("nppdvjthqldpwncqszvftbrmjlhg", 4, 6)]
+    #[case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4, 10)]
+    #[case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4, 11)]
+    #[case("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14, 19)]
+    #[case("nppdvjthqldpwncqszvftbrmjlhg", 14, 23)]
+    #[case("bvwbjplbgvbhsrlpgdmjqwftvncz", 14, 23)]
+    #[case("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14, 29)]
+    #[case("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14, 26)]
+    #[case("ééaé", 2, 3)]
+    fn should_find_marker(
+        #[case] input: &str,
+        #[case] marker_length: usize,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(expected, find_marker(input, marker_length).unwrap_or(0))
+    }
+
+    #[rstest]
+    #[case("abc", 4)]
+    #[case("", 4)]
+    #[case("abcd", 0)]
+    #[case("aaaa", 4)]
+    fn should_not_find_marker(#[case] input: &str, #[case] marker_length: usize) {
+        assert_eq!(None, find_marker(input, marker_length))
+    }
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", Part::A, "7")]
+    #[case("./src/test_inputs/test_input_1.txt", Part::B, "19")]
+    fn should_solve(#[case] path: &str, #[case] part: Part, #[case] expected: &str) {
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let mut reader = BufReader::new(file);
+
+        assert_eq!(expected, Puzzle::solve(&mut reader, part).unwrap());
+    }
+
+    #[rstest]
+    #[case("bvwbjplbgvbhsrlpgdmjqwftvncz\n", "bvwbjplbgvbhsrlpgdmjqwftvncz")]
+    #[case("mjqj", "mjqj")]
+    fn should_parse_signal(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(expected, Puzzle::parse(&mut input.as_bytes()).unwrap());
+    }
+
+    #[rstest]
+    #[case("bvwbjPlbgvbhsr", 1, 6)]
+    #[case("bvwbj lbgvbhsr", 1, 6)]
+    #[case("bvwbjlbgvbhsr\nabc", 2, 1)]
+    fn should_point_to_invalid_signal(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+    ) {
+        match Puzzle::parse(&mut input.as_bytes()) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(line, err.line);
+                assert_eq!(column, err.column);
+            }
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+}
+
+/// Window scan checked against a brute force version comparing every couple of chars in a window.
+#[cfg(test)]
+mod differential_tests {
+    use crate::find_marker;
+    use proptest::prelude::*;
+
+    fn naive_find_marker(input: &str, n: usize) -> Option<usize> {
+        let chars = input.chars().collect::<Vec<char>>();
+
+        if n == 0 {
+            return None;
+        }
+
+        (n..=chars.len()).find(|end| {
+            let window = &chars[end - n..*end];
+            (0..n).all(|i| (i + 1..n).all(|j| window[i] != window[j]))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn marker_agrees_with_naive(input in "[a-h]{0,60}", n in 0usize..10) {
+            prop_assert_eq!(naive_find_marker(&input, n), find_marker(&input, n));
+        }
+
+        #[test]
+        fn marker_of_any_string_agrees_with_naive(input in "\\PC{0,30}", n in 0usize..6) {
+            prop_assert_eq!(naive_find_marker(&input, n), find_marker(&input, n));
+        }
+    }
+}
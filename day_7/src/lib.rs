@@ -0,0 +1,212 @@
+//! [Day 7: No Space Left On Device](https://adventofcode.com/2022/day/7).
+//! Input is a terminal session browsing a file system with: cd and ls.
+
+use aoc_common::{parse::Cursor, Error, Param, Params, Solution};
+use std::{collections::HashMap, io::BufRead};
+use tracing::{debug, trace};
+
+/// From a terminal output made of: `$ cd <dir>`, `$ ls`, `dir <name>` and `<size> <name>` lines,
+/// get size of each directory by path.
+pub fn get_fs(reader: &mut impl BufRead) -> Result<HashMap<String, u128>, Error> {
+    let mut fs = HashMap::new();
+
+    let current_path = &mut vec![];
+
+    for (idx, l) in reader.lines().enumerate() {
+        let line = l?;
+        let line_number = idx + 1;
+
+        let mut cursor = Cursor::new(&line, line_number);
+
+        if let Some(command) = cursor.command() {
+            match (command.name, command.argument) {
+                ("cd", Some("..")) => {
+                    current_path.pop();
+                }
+                ("cd", Some(dir)) => {
+                    current_path.push(dir.to_string());
+                    fs.entry(current_path.join("/")).or_insert(0u128);
+                }
+                ("ls", None) => {}
+                _ => {
+                    return Err(cursor
+                        .error_at(command.span, "Only commands: cd | ls are allowed.")
+                        .into())
+                }
+            }
+            debug!(line = line_number, cwd = %current_path.join("/"), "{}", command.name);
+        } else if cursor.tag("dir ").is_err() {
+            let (file_size, span) = cursor
+                .spanned(Cursor::integer::<u128>)
+                .map_err(|err| err.with_message("File size has to be a number."))?;
+
+            cursor.tag(" ").map_err(|_| {
+                cursor.whole_line("Expected a command, a dir or a file like: 1234 name.txt.")
+            })?;
+            trace!(line = line_number, path = %current_path.join("/"), size = file_size, "file");
+
+            // File is in every dir of current path, compared by name: dir "a" doesn't contain "ab".
+            for depth in 1..=current_path.len() {
+                let size = fs.entry(current_path[..depth].join("/")).or_insert(0u128);
+                *size = size
+                    .checked_add(file_size)
+                    .ok_or_else(|| cursor.error_at(span, "Size of directory is too large."))?;
+            }
+        }
+    }
+
+    Ok(fs)
+}
+
+/// Size of smallest dir that, once deleted, leaves at least `unused_space` free on a disk of `fs_size`.
+/// Returns None when there is no such dir.
+pub fn get_size_of_smallest_dir_that_leaves_unused_space_of(
+    fs: &HashMap<String, u128>,
+    fs_size: u128,
+    unused_space: u128,
+) -> Option<u128> {
+    let mut sizes = fs.values().collect::<Vec<&u128>>();
+    let max = sizes.iter().max()?;
+    let space_to_release = unused_space.saturating_sub(fs_size.saturating_sub(**max));
+
+    sizes.sort();
+
+    for i in sizes {
+        if *i >= space_to_release {
+            return Some(*i);
+        }
+    }
+
+    None
+}
+
+/// Sum of sizes of dirs with size at most `max_size`, nested dirs are counted more than once.
+pub fn get_sum_of_paths_of_size_at_most(fs: &HashMap<String, u128>, max_size: u128) -> u128 {
+    fs.values()
+        .enumerate()
+        .map(|f| f.1)
+        .filter(|f| **f <= max_size)
+        .sum::<u128>()
+}
+
+/// Part A: sum of dirs of at most `max_dir_size` (100000),
+/// part B: smallest dir to delete to get `required_space` (30000000) free out of `disk_size` (70000000).
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "max_dir_size",
+            default: 100000,
+            min: 0,
+            about: "Part A sums dirs of at most this size.",
+        },
+        Param {
+            name: "disk_size",
+            default: 70000000,
+            min: 1,
+            about: "Size of the disk, in part B.",
+        },
+        Param {
+            name: "required_space",
+            default: 30000000,
+            min: 0,
+            about: "Unused space needed by the update, in part B.",
+        },
+    ];
+
+    type Input = HashMap<String, u128>;
+
+    fn parse(reader: &mut impl BufRead) -> Result<Self::Input, Error> {
+        get_fs(reader)
+    }
+
+    fn part_a(input: &Self::Input, params: &Params) -> Result<String, Error> {
+        Ok(get_sum_of_paths_of_size_at_most(input, params.get("max_dir_size") as u128).to_string())
+    }
+
+    fn part_b(input: &Self::Input, params: &Params) -> Result<String, Error> {
+        get_size_of_smallest_dir_that_leaves_unused_space_of(
+            input,
+            params.get("disk_size") as u128,
+            params.get("required_space") as u128,
+        )
+        .map(|size| size.to_string())
+        .ok_or_else(|| Error::NoAnswer(String::from("Unable to find a directory to delete.")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        get_fs, get_size_of_smallest_dir_that_leaves_unused_space_of,
+        get_sum_of_paths_of_size_at_most,
+    };
+    use aoc_common::Error;
+    use rstest::rstest;
+    use std::{fs::File, io::BufReader};
+
+    #[rstest]
+    #[case("./src/test_inputs/test_input_1.txt", 1513699, 7991939)]
+    fn should_get_sizes(#[case] path: &str, #[case] at_most: u128, #[case] smallest: u128) {
+        let file = File::open(path).unwrap_or_else(|_| panic!("Unable to find file: {}", path));
+        let fs = get_fs(&mut BufReader::new(file)).unwrap();
+
+        assert_eq!(at_most, get_sum_of_paths_of_size_at_most(&fs, 100000));
+        assert_eq!(
+            Some(smallest),
+            get_size_of_smallest_dir_that_leaves_unused_space_of(&fs, 70000000, 30000000)
+        );
+    }
+
+    #[rstest]
+    #[case("$ cd /\n$ rm -rf", 2, 3, "rm -rf")]
+    #[case("$ cd /\n$ ls\nabc file.txt", 3, 1, "abc")]
+    #[case("$ cd /\n$ ls\n123", 3, 1, "123")]
+    #[case(
+        "$ cd /\n$ ls\n200000000000000000000000000000000000000 a\n200000000000000000000000000000000000000 b",
+        4,
+        1,
+        "200000000000000000000000000000000000000"
+    )]
+    fn should_point_to_invalid_line(
+        #[case] input: &str,
+        #[case] line: usize,
+        #[case] column: usize,
+        #[case] text: &str,
+    ) {
+        match get_fs(&mut input.as_bytes()) {
+            Err(Error::Parse(err)) => {
+                assert_eq!(line, err.line);
+                assert_eq!(column, err.column);
+                assert_eq!(text, err.text);
+            }
+            other => panic!("Expected a parse error, got: {:?}", other),
+        }
+    }
+
+    #[rstest]
+    #[case(10, 10)]
+    #[case(20, 30)]
+    fn should_not_count_files_of_dirs_with_longer_name(
+        #[case] max_size: u128,
+        #[case] expected: u128,
+    ) {
+        let input = "$ cd /\n$ ls\ndir a\ndir ab\n$ cd a\n$ ls\n10 x\n$ cd ..\n$ cd ab\n$ ls\n20 y";
+        let fs = get_fs(&mut input.as_bytes()).unwrap();
+
+        assert_eq!(expected, get_sum_of_paths_of_size_at_most(&fs, max_size));
+    }
+
+    #[rstest]
+    fn should_not_find_dir_in_empty_fs() {
+        let fs = get_fs(&mut "".as_bytes()).unwrap();
+
+        assert_eq!(
+            None,
+            get_size_of_smallest_dir_that_leaves_unused_space_of(&fs, 70000000, 30000000)
+        );
+    }
+}
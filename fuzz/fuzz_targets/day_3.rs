@@ -0,0 +1,16 @@
+#![no_main]
+
+use aoc_common::{Params, Solution};
+use day_3::Puzzle;
+use libfuzzer_sys::fuzz_target;
+
+// Part A rejects rucksacks with an odd number of items, part B groups may be incomplete.
+fuzz_target!(|input: &[u8]| {
+    let Ok(input) = Puzzle::parse(&mut &input[..]) else {
+        return;
+    };
+    let params = Params::defaults(Puzzle::PARAMS);
+
+    let _ = Puzzle::part_a(&input, &params);
+    let _ = Puzzle::part_b(&input, &params);
+});
@@ -0,0 +1,12 @@
+#!/bin/sh
+# Seed the corpus of each fuzz target with the examples of its day, run from anywhere before `cargo fuzz run`.
+set -eu
+
+root="$(cd "$(dirname "$0")/.." && pwd)"
+
+for inputs in "$root"/day_*/src/test_inputs; do
+    [ -d "$inputs" ] || continue
+    day="$(basename "$(dirname "$(dirname "$inputs")")")"
+    mkdir -p "$root/fuzz/corpus/$day"
+    cp "$inputs"/* "$root/fuzz/corpus/$day/"
+done